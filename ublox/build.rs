@@ -1,3 +1,10 @@
+use std::{collections::HashSet, env, fmt::Write as _, fs, path::Path};
+
+use toml_edit::{DocumentMut, InlineTable, Item, Table, Value};
+
+/// Database of the configuration interface keys
+const CFG_KEYS: &str = "data/cfg_keys.toml";
+
 fn main() {
     #[cfg(all(
         not(feature = "ubx_proto14"),
@@ -9,4 +16,444 @@ fn main() {
     compile_error!(
         "At least one feature 'ubx_protoXX' versions needs to be selected. Please select only one."
     );
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={CFG_KEYS}");
+
+    let src = fs::read_to_string(CFG_KEYS).expect("failed to read the configuration key database");
+    let db = CfgKeyDb::parse(&src);
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("cfg_keys.rs");
+    fs::write(out, db.generate()).expect("failed to write the generated configuration keys");
+}
+
+const PRIMITIVES: [(&str, &str); 10] = [
+    ("bool", "L"),
+    ("u8", "U1"),
+    ("i8", "I1"),
+    ("u16", "U2"),
+    ("i16", "I2"),
+    ("u32", "U4"),
+    ("i32", "I4"),
+    ("u64", "U8"),
+    ("f32", "R4"),
+    ("f64", "R8"),
+];
+
+struct CfgKeyDb {
+    enums: Vec<CfgEnum>,
+    bitflags: Vec<CfgBitflags>,
+    groups: Vec<CfgGroup>,
+    keys: Vec<CfgKeyDesc>,
+}
+
+struct CfgGroup {
+    item: String,
+    name: String,
+    id: u8,
+}
+
+struct CfgEnum {
+    name: String,
+    path: Option<String>,
+    repr: String,
+    value_type: String,
+    cast: bool,
+    doc: Option<String>,
+    values: Vec<CfgEnumValue>,
+}
+
+struct CfgEnumValue {
+    variant: String,
+    value: u64,
+    protocols: Option<Vec<u8>>,
+    doc: Option<String>,
+}
+
+struct CfgBitflags {
+    name: String,
+    path: String,
+    repr: String,
+    retain: bool,
+}
+
+struct CfgKeyDesc {
+    item: String,
+    name: String,
+    id: u32,
+    ty: String,
+    scale: Option<f64>,
+    unit: Option<String>,
+    protocols: Vec<u8>,
+    doc: Option<String>,
+}
+
+fn str_field(table: &InlineTable, field: &str) -> Option<String> {
+    table.get(field).map(|v| {
+        v.as_str()
+            .unwrap_or_else(|| panic!("`{field}` must be a string"))
+            .to_owned()
+    })
+}
+
+fn int_field(table: &InlineTable, field: &str) -> Option<i64> {
+    table.get(field).map(|v| {
+        v.as_integer()
+            .unwrap_or_else(|| panic!("`{field}` must be an integer"))
+    })
+}
+
+fn protocols(value: Option<&Value>) -> Option<Vec<u8>> {
+    value.map(|v| {
+        v.as_array()
+            .expect("`protocols` must be an array")
+            .iter()
+            .map(|p| p.as_integer().expect("protocol versions must be integers") as u8)
+            .collect()
+    })
+}
+
+fn table_str(table: &Table, field: &str) -> Option<String> {
+    table.get(field).map(|v| {
+        v.as_str()
+            .unwrap_or_else(|| panic!("`{field}` must be a string"))
+            .to_owned()
+    })
+}
+
+fn inline_tables<'a>(
+    item: Option<&'a Item>,
+    what: &'a str,
+) -> impl Iterator<Item = &'a InlineTable> {
+    item.and_then(Item::as_array)
+        .unwrap_or_else(|| panic!("{what} must be an array"))
+        .iter()
+        .map(move |v| {
+            v.as_inline_table()
+                .unwrap_or_else(|| panic!("{what} must only contain inline tables"))
+        })
+}
+
+impl CfgKeyDb {
+    fn parse(src: &str) -> Self {
+        let doc: DocumentMut = src.parse().expect("invalid configuration key database");
+        let default_protocols =
+            protocols(doc.get("protocols").and_then(Item::as_value)).expect("missing `protocols`");
+
+        let mut enums = Vec::new();
+        if let Some(table) = doc.get("enums").and_then(Item::as_table) {
+            for (name, item) in table {
+                let desc = item.as_table().expect("enums must be tables");
+                let values = inline_tables(desc.get("values"), name)
+                    .map(|v| CfgEnumValue {
+                        variant: str_field(v, "variant").expect("missing enum variant"),
+                        value: int_field(v, "value").expect("missing enum value") as u64,
+                        protocols: protocols(v.get("protocols")),
+                        doc: str_field(v, "doc"),
+                    })
+                    .collect();
+                let repr = table_str(desc, "repr").expect("missing enum repr");
+                enums.push(CfgEnum {
+                    name: name.to_owned(),
+                    path: table_str(desc, "path"),
+                    value_type: table_str(desc, "type")
+                        .unwrap_or_else(|| enum_value_type(&repr).to_owned()),
+                    repr,
+                    cast: desc.get("cast").and_then(Item::as_bool).unwrap_or(false)
+                        || !desc.contains_key("path"),
+                    doc: table_str(desc, "doc"),
+                    values,
+                });
+            }
+        }
+
+        let mut bitflags = Vec::new();
+        if let Some(table) = doc.get("bitflags").and_then(Item::as_table) {
+            for (name, item) in table {
+                let desc = item.as_table().expect("bitflags must be tables");
+                bitflags.push(CfgBitflags {
+                    name: name.to_owned(),
+                    path: table_str(desc, "path").expect("missing bitflags path"),
+                    repr: table_str(desc, "repr").expect("missing bitflags repr"),
+                    retain: desc.get("retain").and_then(Item::as_bool).unwrap_or(false),
+                });
+            }
+        }
+
+        let mut keys = Vec::new();
+        let mut cfg_groups = Vec::new();
+        let groups = doc
+            .get("groups")
+            .and_then(Item::as_array_of_tables)
+            .expect("missing `groups`");
+        for group in groups {
+            let group_name = table_str(group, "name");
+            let group_protocols = protocols(group.get("protocols").and_then(Item::as_value))
+                .unwrap_or_else(|| default_protocols.clone());
+            let group_keys = keys.len();
+            for key in inline_tables(group.get("keys"), "group keys") {
+                let item = str_field(key, "item").expect("missing key item");
+                let id = int_field(key, "id").unwrap_or_else(|| panic!("{item}: missing id"));
+                let id = u32::try_from(id).unwrap_or_else(|_| panic!("{item}: invalid id"));
+                let name = match (&group_name, str_field(key, "name")) {
+                    (Some(group), Some(name)) => format!("CFG-{group}-{name}"),
+                    (None, None) => format!("0x{id:08x}"),
+                    _ => panic!("{item}: keys need a name if and only if their group has one"),
+                };
+                let scale = key.get("scale").map(|v| {
+                    v.as_float()
+                        .or_else(|| v.as_integer().map(|i| i as f64))
+                        .unwrap_or_else(|| panic!("{item}: scale must be a number"))
+                });
+                keys.push(CfgKeyDesc {
+                    name,
+                    id,
+                    ty: str_field(key, "type").unwrap_or_else(|| panic!("{item}: missing type")),
+                    scale,
+                    unit: str_field(key, "unit"),
+                    protocols: protocols(key.get("protocols"))
+                        .unwrap_or_else(|| group_protocols.clone()),
+                    doc: str_field(key, "doc"),
+                    item,
+                });
+            }
+            if let Some(name) = group_name {
+                let item = table_str(group, "item")
+                    .unwrap_or_else(|| panic!("group {name}: missing item"));
+                let id = (keys[group_keys].id >> 16) as u8;
+                assert!(
+                    keys[group_keys..].iter().all(|k| (k.id >> 16) as u8 == id),
+                    "group {name}: keys of different groups"
+                );
+                cfg_groups.push(CfgGroup { item, name, id });
+            }
+        }
+
+        let db = Self {
+            enums,
+            bitflags,
+            groups: cfg_groups,
+            keys,
+        };
+        db.check();
+        db
+    }
+
+    fn check(&self) {
+        let mut groups = HashSet::new();
+        for group in &self.groups {
+            assert!(groups.insert(group.id), "duplicate group {}", group.name);
+        }
+
+        let mut items = HashSet::new();
+        let mut names = HashSet::new();
+        let mut ids = HashSet::new();
+        for key in &self.keys {
+            assert!(items.insert(&key.item), "duplicate item {}", key.item);
+            assert!(names.insert(&key.name), "duplicate name {}", key.name);
+            assert!(ids.insert(key.id), "duplicate key ID 0x{:08x}", key.id);
+            assert!(
+                PRIMITIVES.iter().any(|(ty, _)| *ty == key.ty)
+                    || self.enums.iter().any(|e| e.name == key.ty)
+                    || self.bitflags.iter().any(|b| b.name == key.ty),
+                "{}: unknown type {}",
+                key.item,
+                key.ty
+            );
+        }
+    }
+
+    fn generate(&self) -> String {
+        let mut out = String::new();
+        writeln!(
+            out,
+            "// Generated by build.rs from {CFG_KEYS}, do not edit."
+        )
+        .unwrap();
+        for path in self
+            .enums
+            .iter()
+            .filter_map(|e| e.path.as_ref())
+            .chain(self.bitflags.iter().map(|b| &b.path))
+        {
+            writeln!(out, "use {path};").unwrap();
+        }
+
+        for e in &self.enums {
+            if e.path.is_none() {
+                e.write_definition(&mut out);
+            }
+            e.write_cfg_value(&mut out);
+        }
+        for b in &self.bitflags {
+            b.write_cfg_value(&mut out);
+        }
+
+        out.push_str("\ncfg_val! {\nwildcards {\n");
+        for group in &self.groups {
+            writeln!(
+                out,
+                "/// All the `CFG-{}-*` keys\nWildcard{} = 0x{:08x};",
+                group.name,
+                group.item,
+                0x7f00_ffff | u32::from(group.id) << 16
+            )
+            .unwrap();
+        }
+        out.push_str("}\n");
+        for key in &self.keys {
+            write_doc(&mut out, key.doc.as_deref());
+            writeln!(out, "{}, 0x{:08x}, {},", key.item, key.id, key.ty).unwrap();
+        }
+        out.push_str("}\n");
+
+        out.push_str("\n/// Descriptions of all the known configuration keys\n");
+        out.push_str("pub static CFG_KEYS: &[CfgKeyInfo] = &[\n");
+        for key in &self.keys {
+            writeln!(
+                out,
+                "CfgKeyInfo {{ key: CfgKey::{item}, name: {name:?}, value_type: <{ty} as CfgValue>::TYPE, \
+                 scale: {scale:?}, unit: {unit:?}, values: <{ty} as CfgValue>::VALUES, \
+                 protocols: &{protocols:?}, description: {doc:?} }},",
+                item = key.item,
+                name = key.name,
+                ty = key.ty,
+                scale = key.scale,
+                unit = key.unit,
+                protocols = key.protocols,
+                doc = key.doc.as_deref().unwrap_or_default(),
+            )
+            .unwrap();
+        }
+        out.push_str("];\n");
+        out
+    }
+}
+
+fn write_doc(out: &mut String, doc: Option<&str>) {
+    for line in doc.into_iter().flat_map(str::lines) {
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            writeln!(out, "/// {line}").unwrap();
+        }
+    }
+}
+
+fn cfg_attr(protocols: &Option<Vec<u8>>) -> String {
+    match protocols {
+        Some(protocols) => {
+            let features: Vec<String> = protocols
+                .iter()
+                .map(|p| format!("feature = \"ubx_proto{p}\""))
+                .collect();
+            format!("#[cfg(any({}))]\n", features.join(", "))
+        },
+        None => String::new(),
+    }
+}
+
+fn enum_value_type(repr: &str) -> &'static str {
+    match repr {
+        "u8" => "E1",
+        "u16" => "E2",
+        "u32" => "E4",
+        _ => panic!("unsupported enum repr {repr}"),
+    }
+}
+
+fn bitflags_value_type(repr: &str) -> &'static str {
+    match repr {
+        "u8" => "X1",
+        "u16" => "X2",
+        "u32" => "X4",
+        "u64" => "X8",
+        _ => panic!("unsupported bitflags repr {repr}"),
+    }
+}
+
+impl CfgEnum {
+    fn write_definition(&self, out: &mut String) {
+        out.push('\n');
+        write_doc(out, self.doc.as_deref());
+        out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n");
+        out.push_str(
+            "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]\n",
+        );
+        writeln!(out, "pub enum {} {{", self.name).unwrap();
+        for v in &self.values {
+            write_doc(out, v.doc.as_deref());
+            out.push_str(&cfg_attr(&v.protocols));
+            writeln!(out, "{} = {},", v.variant, v.value).unwrap();
+        }
+        out.push_str("}\n");
+    }
+
+    fn write_cfg_value(&self, out: &mut String) {
+        let name = &self.name;
+        let repr = &self.repr;
+        writeln!(out, "\nimpl CfgValue for {name} {{").unwrap();
+        writeln!(
+            out,
+            "const TYPE: CfgValueType = CfgValueType::{};",
+            self.value_type
+        )
+        .unwrap();
+        out.push_str("const VALUES: &'static [CfgEnumValue] = &[\n");
+        for v in &self.values {
+            out.push_str(&cfg_attr(&v.protocols));
+            writeln!(
+                out,
+                "CfgEnumValue {{ name: {:?}, value: {} }},",
+                v.variant, v.value
+            )
+            .unwrap();
+        }
+        out.push_str("];\n");
+
+        out.push_str("fn from_cfg_bytes(buf: &[u8]) -> Option<Self> {\n");
+        writeln!(out, "match {repr}::from_cfg_bytes(buf)? {{").unwrap();
+        for v in &self.values {
+            out.push_str(&cfg_attr(&v.protocols));
+            writeln!(out, "{} => Some(Self::{}),", v.value, v.variant).unwrap();
+        }
+        out.push_str("_ => None,\n}\n}\n");
+
+        out.push_str("fn write_cfg_bytes(self, buf: &mut [u8]) {\n");
+        if self.cast {
+            writeln!(out, "(self as {repr}).write_cfg_bytes(buf)\n}}\n}}").unwrap();
+            return;
+        }
+        writeln!(out, "let value: {repr} = match self {{").unwrap();
+        for v in &self.values {
+            out.push_str(&cfg_attr(&v.protocols));
+            writeln!(out, "Self::{} => {},", v.variant, v.value).unwrap();
+        }
+        out.push_str("};\nvalue.write_cfg_bytes(buf)\n}\n}\n");
+    }
+}
+
+impl CfgBitflags {
+    fn write_cfg_value(&self, out: &mut String) {
+        let from_bits = if self.retain {
+            "from_bits_retain"
+        } else {
+            "from_bits_truncate"
+        };
+        write!(
+            out,
+            "\nimpl CfgValue for {name} {{\n\
+             const TYPE: CfgValueType = CfgValueType::{ty};\n\
+             fn from_cfg_bytes(buf: &[u8]) -> Option<Self> {{\n\
+             {repr}::from_cfg_bytes(buf).map(Self::{from_bits})\n\
+             }}\n\
+             fn write_cfg_bytes(self, buf: &mut [u8]) {{\n\
+             self.bits().write_cfg_bytes(buf)\n\
+             }}\n\
+             }}\n",
+            name = self.name,
+            ty = bitflags_value_type(&self.repr),
+            repr = self.repr,
+        )
+        .unwrap();
+    }
 }
@@ -1,5 +1,10 @@
 use core::fmt;
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::cfg_val::CfgKey;
+
 #[derive(Debug)]
 pub enum MemWriterError<E> {
     NotEnoughMem,
@@ -89,3 +94,164 @@ impl fmt::Display for DateTimeError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for DateTimeError {}
+
+/// Configuration Value that could not be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CfgValError {
+    /// The buffer is shorter than the key ID and its value
+    Truncated,
+    /// The size bits of the key ID are reserved, so the value cannot be skipped
+    InvalidKeyId(u32),
+    /// The value is not valid for the key, e.g. an enumeration value added by
+    /// a newer firmware
+    InvalidValue(CfgKey),
+}
+
+impl fmt::Display for CfgValError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CfgValError::Truncated => f.write_str("truncated configuration value"),
+            CfgValError::InvalidKeyId(key_id) => {
+                write!(f, "invalid storage size in key ID 0x{key_id:08x}")
+            },
+            CfgValError::InvalidValue(key) => write!(f, "invalid value for {key:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CfgValError {}
+
+/// Receiver profile that cannot be converted to configuration values
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileError {
+    /// No configuration key has this name
+    UnknownKey(String),
+    /// The value of the named key does not fit its type
+    InvalidValue(String),
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::UnknownKey(name) => write!(f, "unknown configuration key {name}"),
+            ProfileError::InvalidValue(name) => write!(f, "invalid value for {name}"),
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+impl std::error::Error for ProfileError {}
+
+/// u-center configuration file that cannot be read, with the 1-based number
+/// of the offending line
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UcenterError {
+    /// The line is neither a configuration value nor a message
+    InvalidLine(usize),
+    /// The message bytes are not valid hex or do not match the length field
+    InvalidMessage(usize),
+    /// The configuration value cannot be encoded for its key
+    InvalidValue(usize, ProfileError),
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for UcenterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UcenterError::InvalidLine(line) => write!(f, "line {line}: unrecognized line"),
+            UcenterError::InvalidMessage(line) => write!(f, "line {line}: invalid message"),
+            UcenterError::InvalidValue(line, err) => write!(f, "line {line}: {err}"),
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "std"))]
+impl std::error::Error for UcenterError {}
+
+/// Power management settings that the receiver would not accept
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerManagementError {
+    /// ON phase is not shorter than the update period
+    OnTimeNotShorterThanPeriod,
+    /// Interval period must be longer than 5 s
+    PeriodTooShort,
+    /// Period and ON time are only allowed with the interval power setup
+    UnexpectedInterval,
+    /// Power setup value is only reported when polling
+    InvalidPowerSetup,
+    /// The request does not set the backup flag
+    BackupFlagMissing,
+    /// Requested duration is longer than 12 days
+    DurationTooLong,
+    /// Infinite backup without any wake-up source
+    NoWakeupSource,
+}
+
+impl fmt::Display for PowerManagementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PowerManagementError::OnTimeNotShorterThanPeriod => {
+                f.write_str("ON time must be shorter than the update period")
+            },
+            PowerManagementError::PeriodTooShort => {
+                f.write_str("interval period must be longer than 5 s")
+            },
+            PowerManagementError::UnexpectedInterval => {
+                f.write_str("period and ON time must be zero unless the power setup is interval")
+            },
+            PowerManagementError::InvalidPowerSetup => {
+                f.write_str("invalid power setup value cannot be set")
+            },
+            PowerManagementError::BackupFlagMissing => {
+                f.write_str("power management request without backup flag")
+            },
+            PowerManagementError::DurationTooLong => f.write_str("duration longer than 12 days"),
+            PowerManagementError::NoWakeupSource => {
+                f.write_str("infinite backup without wake-up source")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PowerManagementError {}
+
+/// Message stream signature that could not be verified
+#[cfg(feature = "sec-verify")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecVerifyError {
+    /// The last hashed message differs from the one named by the signature
+    LastMessageMismatch,
+    /// Number of hashed messages differs from the one reported by the signature
+    MessageCount { expected: u16, got: u16 },
+    /// The hash of the message stream differs from the signed one
+    HashMismatch,
+    /// The signature was produced for another session
+    SessionIdMismatch,
+    /// The ECDSA signature is malformed or does not match the public key
+    InvalidSignature,
+}
+
+#[cfg(feature = "sec-verify")]
+impl fmt::Display for SecVerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SecVerifyError::LastMessageMismatch => {
+                f.write_str("last signed message does not match the message stream")
+            },
+            SecVerifyError::MessageCount { expected, got } => {
+                write!(f, "expected {expected} signed messages, got {got}")
+            },
+            SecVerifyError::HashMismatch => f.write_str("message stream hash mismatch"),
+            SecVerifyError::SessionIdMismatch => f.write_str("session ID mismatch"),
+            SecVerifyError::InvalidSignature => f.write_str("invalid ECDSA signature"),
+        }
+    }
+}
+
+#[cfg(all(feature = "sec-verify", feature = "std"))]
+impl std::error::Error for SecVerifyError {}
@@ -8,7 +8,7 @@ extern crate core;
 extern crate serde;
 
 pub use crate::{
-    error::{DateTimeError, MemWriterError, ParserError},
+    error::{CfgValError, DateTimeError, MemWriterError, ParserError, PowerManagementError},
     parser::{
         AnyPacketRef, FixedBuffer, FixedLinearBuffer, NmeaPacketRef, Parser, ParserBuilder,
         RtcmPacketRef, UbxParserIter, UnderlyingBuffer,
@@ -16,6 +16,11 @@ pub use crate::{
     ubx_packets::*,
 };
 
+#[cfg(feature = "sec-verify")]
+pub use crate::error::SecVerifyError;
+#[cfg(feature = "alloc")]
+pub use crate::error::{ProfileError, UcenterError};
+
 mod error;
 mod parser;
 mod ubx_packets;
@@ -63,6 +68,40 @@ pub enum UbxPacket<'a> {
     Proto33(proto33::PacketRef<'a>),
 }
 
+impl UbxPacket<'_> {
+    /// Class and message ID of the packet
+    pub fn class_and_msg_id(&self) -> (u8, u8) {
+        match *self {
+            #[cfg(feature = "ubx_proto14")]
+            UbxPacket::Proto14(ref packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto23")]
+            UbxPacket::Proto23(ref packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto27")]
+            UbxPacket::Proto27(ref packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto31")]
+            UbxPacket::Proto31(ref packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto33")]
+            UbxPacket::Proto33(ref packet) => packet.class_and_msg_id(),
+        }
+    }
+
+    /// Payload of the packet, as received
+    pub fn payload(&self) -> &[u8] {
+        match *self {
+            #[cfg(feature = "ubx_proto14")]
+            UbxPacket::Proto14(ref packet) => packet.payload(),
+            #[cfg(feature = "ubx_proto23")]
+            UbxPacket::Proto23(ref packet) => packet.payload(),
+            #[cfg(feature = "ubx_proto27")]
+            UbxPacket::Proto27(ref packet) => packet.payload(),
+            #[cfg(feature = "ubx_proto31")]
+            UbxPacket::Proto31(ref packet) => packet.payload(),
+            #[cfg(feature = "ubx_proto33")]
+            UbxPacket::Proto33(ref packet) => packet.payload(),
+        }
+    }
+}
+
 /// Trait for parsing UBX protocol version.
 pub trait UbxProtocol: Send + Sized {
     /// The protocol-specific PacketRef type. The `'a` lifetime is tied to the input buffer.
@@ -1,6 +1,10 @@
+#[cfg(feature = "alloc")]
+pub mod cfg_profile;
 pub mod cfg_val;
 pub mod packets;
 mod types;
+#[cfg(feature = "alloc")]
+pub mod ucenter;
 
 use crate::{constants::UBX_HEADER_LEN, constants::UBX_SYNC_SIZE, error::MemWriterError};
 pub use packets::*;
@@ -0,0 +1,437 @@
+//! Receiver configuration profiles
+//!
+//! A [ReceiverProfile] holds the values of configuration keys by their u-blox
+//! name, e.g. `CFG-RATE-MEAS`, so that it can be kept in a TOML or JSON file
+//! with the `serde` feature:
+//!
+//! ```toml
+//! "CFG-RATE-MEAS" = 100
+//! "CFG-NAVSPG-DYNMODEL" = "Automotive"
+//! "CFG-SIGNAL-GPS_L5_ENA" = true
+//! ```
+//!
+//! Values are raw, unscaled values. Enumerations are given by the name of
+//! their variant, or by their value. Keys unknown to this crate are named after
+//! their key ID, e.g. `0x10320001`.
+//!
+//! [ReceiverProfile::diff] compares a profile with the configuration read
+//! from the receiver, e.g. with [CfgValDump](crate::packets::cfg_val::CfgValDump),
+//! and returns the values to set.
+
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+
+use crate::{
+    cfg_val::{CfgEnumValue, CfgKeyInfo, CfgVal, CfgValueType, KeyId, StorageSize},
+    error::ProfileError,
+    packets::cfg_val::{CfgLayerSet, CfgValSetBuilder, MAX_CFG_KEYS},
+};
+
+/// Value of a configuration key in a [ReceiverProfile]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum ProfileValue {
+    Bool(bool),
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+    /// Name of an enumeration value, see [CfgKeyInfo::values]
+    Name(String),
+}
+
+impl ProfileValue {
+    fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Unsigned(value) => Some(*value),
+            Self::Signed(value) => u64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::Unsigned(value) => i64::try_from(*value).ok(),
+            Self::Signed(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Unsigned(value) => Some(*value as f64),
+            Self::Signed(value) => Some(*value as f64),
+            Self::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// Receiver configuration, as values of configuration keys by u-blox name
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ReceiverProfile {
+    values: BTreeMap<String, ProfileValue>,
+}
+
+impl ReceiverProfile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of the key named `name`, returning its previous value
+    pub fn insert(&mut self, name: impl Into<String>, value: ProfileValue) -> Option<ProfileValue> {
+        self.values.insert(name.into(), value)
+    }
+
+    /// Sets the key of `val` to its value
+    pub fn set(&mut self, val: &CfgVal) {
+        let (name, value) = decode(val, true);
+        self.values.insert(name, value);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ProfileValue> {
+        self.values.get(name)
+    }
+
+    /// Key names and values, sorted by name
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ProfileValue)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Configuration values of the profile
+    pub fn to_cfg_vals(&self) -> Result<Vec<CfgVal>, ProfileError> {
+        self.values
+            .iter()
+            .map(|(name, value)| encode(name, value))
+            .collect()
+    }
+
+    /// Values of the profile that differ from, or are missing in, the
+    /// `current` configuration of the receiver
+    pub fn diff(
+        &self,
+        current: impl IntoIterator<Item = CfgVal>,
+    ) -> Result<ProfileDiff, ProfileError> {
+        let current: BTreeMap<u32, CfgVal> =
+            current.into_iter().map(|val| (val.key_id(), val)).collect();
+        let changes = self
+            .to_cfg_vals()?
+            .into_iter()
+            .filter(|val| current.get(&val.key_id()) != Some(val))
+            .collect();
+        Ok(ProfileDiff { changes })
+    }
+}
+
+impl FromIterator<CfgVal> for ReceiverProfile {
+    fn from_iter<T: IntoIterator<Item = CfgVal>>(iter: T) -> Self {
+        let mut profile = Self::new();
+        for val in iter {
+            profile.set(&val);
+        }
+        profile
+    }
+}
+
+/// Values to set for the receiver configuration to match a [ReceiverProfile]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProfileDiff {
+    changes: Vec<CfgVal>,
+}
+
+impl ProfileDiff {
+    pub fn changes(&self) -> &[CfgVal] {
+        &self.changes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// UBX-CFG-VALSET requests setting the changed values in `layers`, with at
+    /// most [MAX_CFG_KEYS] values each
+    pub fn requests(&self, layers: CfgLayerSet) -> impl Iterator<Item = CfgValSetBuilder<'_>> {
+        self.changes
+            .chunks(usize::from(MAX_CFG_KEYS))
+            .map(move |cfg_data| CfgValSetBuilder {
+                version: 0,
+                layers,
+                reserved1: 0,
+                cfg_data,
+            })
+    }
+
+    /// Sends the requests with `send`, `ack` returning whether the receiver
+    /// answered each one with UBX-ACK-ACK rather than UBX-ACK-NAK.
+    ///
+    /// The receiver applies or rejects a request as a whole, the values of the
+    /// rejected requests are returned.
+    pub fn apply<E>(
+        &self,
+        layers: CfgLayerSet,
+        mut send: impl FnMut(&[u8]) -> Result<(), E>,
+        mut ack: impl FnMut() -> Result<bool, E>,
+    ) -> Result<Vec<CfgVal>, E> {
+        let mut rejected = Vec::new();
+        for request in self.requests(layers) {
+            let cfg_data = request.cfg_data;
+            send(&request.into_packet_vec())?;
+            if !ack()? {
+                rejected.extend_from_slice(cfg_data);
+            }
+        }
+        Ok(rejected)
+    }
+}
+
+fn key_name(key_id: u32) -> String {
+    match CfgKeyInfo::from_id(key_id) {
+        Some(info) => info.name.into(),
+        None => format!("0x{key_id:08x}"),
+    }
+}
+
+fn key_id(name: &str) -> Option<u32> {
+    if let Some(info) = CfgKeyInfo::from_name(name) {
+        return Some(info.key as u32);
+    }
+    let key_id = u32::from_str_radix(name.strip_prefix("0x")?, 16).ok()?;
+    KeyId(key_id).checked_value_size().map(|_| key_id)
+}
+
+/// Type and named values of a key, keys unknown to this crate are unsigned
+fn value_desc(key_id: u32) -> (CfgValueType, &'static [CfgEnumValue]) {
+    if let Some(info) = CfgKeyInfo::from_id(key_id) {
+        return (info.value_type, info.values);
+    }
+    let value_type = match KeyId(key_id).value_size() {
+        StorageSize::OneBit => CfgValueType::L,
+        StorageSize::OneByte => CfgValueType::U1,
+        StorageSize::TwoBytes => CfgValueType::U2,
+        StorageSize::FourBytes => CfgValueType::U4,
+        StorageSize::EightBytes => CfgValueType::U8,
+    };
+    (value_type, &[])
+}
+
+/// Value of the key named `name`
+pub(crate) fn encode(name: &str, value: &ProfileValue) -> Result<CfgVal, ProfileError> {
+    let key_id = key_id(name).ok_or_else(|| ProfileError::UnknownKey(name.into()))?;
+    let (value_type, named) = value_desc(key_id);
+    let bits = 8 * value_type.storage_size().to_usize() as u32;
+    let raw = match (value_type, value) {
+        (_, ProfileValue::Name(variant)) => named
+            .iter()
+            .find(|v| v.name == variant)
+            .map(|v| u64::from(v.value)),
+        (CfgValueType::L, ProfileValue::Bool(value)) => Some(u64::from(*value)),
+        (_, ProfileValue::Bool(_)) => None,
+        (CfgValueType::R4, value) => value.as_f64().map(|v| u64::from((v as f32).to_bits())),
+        (CfgValueType::R8, value) => value.as_f64().map(f64::to_bits),
+        (CfgValueType::I1 | CfgValueType::I2 | CfgValueType::I4 | CfgValueType::I8, value) => value
+            .as_i64()
+            .filter(|v| {
+                let min = i64::MIN >> (64 - bits);
+                let max = i64::MAX >> (64 - bits);
+                (min..=max).contains(v)
+            })
+            .map(|v| v as u64),
+        (CfgValueType::L, value) => value.as_u64().filter(|v| *v <= 1),
+        (_, value) => value.as_u64().filter(|v| bits == 64 || v >> bits == 0),
+    }
+    .ok_or_else(|| ProfileError::InvalidValue(name.into()))?;
+
+    let mut buf = [0; 12];
+    buf[..4].copy_from_slice(&key_id.to_le_bytes());
+    buf[4..].copy_from_slice(&raw.to_le_bytes());
+    CfgVal::parse(&buf).map_err(|_| ProfileError::InvalidValue(name.into()))
+}
+
+/// Name of the key of `val` and its value, by variant name for enumerations
+/// if `named`
+pub(crate) fn decode(val: &CfgVal, named: bool) -> (String, ProfileValue) {
+    let mut buf = [0; 12];
+    let len = val.write_to(&mut buf);
+    let mut raw = [0; 8];
+    raw[..len - 4].copy_from_slice(&buf[4..len]);
+    let raw = u64::from_le_bytes(raw);
+    let bits = 8 * (len as u32 - 4);
+
+    let key_id = val.key_id();
+    let (value_type, values) = value_desc(key_id);
+    let variant = values.iter().find(|v| named && u64::from(v.value) == raw);
+    let value = match variant {
+        Some(v) => ProfileValue::Name(v.name.into()),
+        None => match value_type {
+            CfgValueType::L => ProfileValue::Bool(raw != 0),
+            CfgValueType::R4 => ProfileValue::Float(f32::from_bits(raw as u32).into()),
+            CfgValueType::R8 => ProfileValue::Float(f64::from_bits(raw)),
+            CfgValueType::I1 | CfgValueType::I2 | CfgValueType::I4 | CfgValueType::I8 => {
+                ProfileValue::Signed(((raw << (64 - bits)) as i64) >> (64 - bits))
+            },
+            _ => ProfileValue::Unsigned(raw),
+        },
+    };
+    (key_name(key_id), value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfg_val::{TxReadyPolarity, UnknownCfgVal};
+    use crate::packets::cfg_nav5::NavDynamicModel;
+    use alloc::vec;
+
+    fn profile() -> ReceiverProfile {
+        let mut profile = ReceiverProfile::new();
+        profile.insert("CFG-RATE-MEAS", ProfileValue::Unsigned(100));
+        profile.insert(
+            "CFG-NAVSPG-DYNMODEL",
+            ProfileValue::Name("Automotive".into()),
+        );
+        profile.insert("CFG-TP-ANT_CABLEDELAY", ProfileValue::Signed(-50));
+        profile.insert("CFG-NAVSPG-USRDAT_MAJA", ProfileValue::Float(6378137.0));
+        profile.insert(
+            "CFG-TXREADY-POLARITY",
+            ProfileValue::Name("ActiveLow".into()),
+        );
+        profile.insert("CFG-SIGNAL-GPS_L5_ENA", ProfileValue::Bool(true));
+        profile.insert("0x20fe0001", ProfileValue::Unsigned(7));
+        profile
+    }
+
+    fn values() -> Vec<CfgVal> {
+        vec![
+            CfgVal::Unknown(UnknownCfgVal::new(0x20fe0001, &[7]).unwrap()),
+            CfgVal::NavSpgDynModel(NavDynamicModel::Automotive),
+            CfgVal::NavSpgUsrDatMaja(6378137.0),
+            CfgVal::RateMeas(100),
+            CfgVal::SignalGpsL5Ena(true),
+            CfgVal::TpAntCableDelay(-50),
+            CfgVal::TxreadyPolarity(TxReadyPolarity::ActiveLow),
+        ]
+    }
+
+    #[test]
+    fn profile_values() {
+        assert_eq!(profile().to_cfg_vals().unwrap(), values());
+        assert_eq!(values().into_iter().collect::<ReceiverProfile>(), profile());
+    }
+
+    #[test]
+    fn invalid_profiles() {
+        let invalid = [
+            ("CFG-RATE-NOPE", ProfileValue::Unsigned(1)),
+            ("0x70fe0001", ProfileValue::Unsigned(1)),
+            ("CFG-RATE-MEAS", ProfileValue::Unsigned(0x10000)),
+            ("CFG-RATE-MEAS", ProfileValue::Bool(true)),
+            ("CFG-TP-ANT_CABLEDELAY", ProfileValue::Signed(-32769)),
+            ("CFG-NAVSPG-DYNMODEL", ProfileValue::Name("Rocket".into())),
+            ("CFG-NAVSPG-DYNMODEL", ProfileValue::Unsigned(200)),
+            ("CFG-SIGNAL-GPS_L5_ENA", ProfileValue::Unsigned(2)),
+        ];
+        for (name, value) in invalid {
+            let mut profile = ReceiverProfile::new();
+            profile.insert(name, value);
+            let expected = if name.contains("NOPE") || name.starts_with("0x") {
+                ProfileError::UnknownKey(name.into())
+            } else {
+                ProfileError::InvalidValue(name.into())
+            };
+            assert_eq!(profile.to_cfg_vals(), Err(expected));
+        }
+
+        // Enumeration values may be given as numbers
+        let mut profile = ReceiverProfile::new();
+        profile.insert("CFG-NAVSPG-DYNMODEL", ProfileValue::Unsigned(4));
+        assert_eq!(
+            profile.to_cfg_vals(),
+            Ok(vec![CfgVal::NavSpgDynModel(NavDynamicModel::Automotive)])
+        );
+    }
+
+    #[test]
+    fn diff_with_current_configuration() {
+        let current = [
+            CfgVal::RateMeas(1000),
+            CfgVal::SignalGpsL5Ena(true),
+            CfgVal::TpAntCableDelay(-50),
+            CfgVal::Uart1Baudrate(38400),
+        ];
+        let diff = profile().diff(current).unwrap();
+        assert_eq!(
+            diff.changes(),
+            [
+                CfgVal::Unknown(UnknownCfgVal::new(0x20fe0001, &[7]).unwrap()),
+                CfgVal::NavSpgDynModel(NavDynamicModel::Automotive),
+                CfgVal::NavSpgUsrDatMaja(6378137.0),
+                CfgVal::RateMeas(100),
+                CfgVal::TxreadyPolarity(TxReadyPolarity::ActiveLow),
+            ]
+        );
+        assert!(profile().diff(values()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn apply_reports_rejected_chunk() {
+        let profile: ReceiverProfile = (0..70u32)
+            .map(|id| CfgVal::Unknown(UnknownCfgVal::new(0x20fe_0000 | id, &[1]).unwrap()))
+            .collect();
+        let diff = profile.diff([]).unwrap();
+        assert_eq!(diff.changes().len(), 70);
+
+        let requests: Vec<_> = diff.requests(CfgLayerSet::RAM).collect();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].cfg_data.len(), 64);
+        assert_eq!(requests[1].cfg_data.len(), 6);
+
+        let mut sent = Vec::new();
+        let mut acks = [true, false].into_iter();
+        let rejected = diff
+            .apply::<()>(
+                CfgLayerSet::RAM | CfgLayerSet::BBR,
+                |request| {
+                    sent.push(request.to_vec());
+                    Ok(())
+                },
+                || Ok(acks.next().unwrap()),
+            )
+            .unwrap();
+        assert_eq!(rejected, diff.changes()[64..]);
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0][2..4], [0x06, 0x8a]);
+        assert_eq!(sent[0][7], 0b011);
+        assert_eq!(u16::from_le_bytes([sent[1][4], sent[1][5]]), 4 + 6 * 5);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn json_profile() {
+        let json = r#"{
+            "0x20fe0001": 7,
+            "CFG-NAVSPG-DYNMODEL": "Automotive",
+            "CFG-NAVSPG-USRDAT_MAJA": 6378137.0,
+            "CFG-RATE-MEAS": 100,
+            "CFG-SIGNAL-GPS_L5_ENA": true,
+            "CFG-TP-ANT_CABLEDELAY": -50,
+            "CFG-TXREADY-POLARITY": "ActiveLow"
+        }"#;
+        let parsed: ReceiverProfile = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed, profile());
+
+        let written = serde_json::to_string(&parsed).unwrap();
+        let parsed: ReceiverProfile = serde_json::from_str(&written).unwrap();
+        assert_eq!(parsed, profile());
+    }
+}
@@ -14,25 +14,41 @@ pub mod packetref_proto33;
 
 pub mod ack;
 
+pub mod aid_alm;
+pub mod aid_alp;
+pub mod aid_aop;
+pub mod aid_eph;
+pub mod aid_hui;
 pub mod aid_ini;
 
 pub mod cfg_ant;
+pub mod cfg_dat;
+pub mod cfg_dgnss;
 pub mod cfg_esf_alg;
 pub mod cfg_esf_wt;
+pub mod cfg_geofence;
 pub mod cfg_gnss;
+pub mod cfg_hnr;
 pub mod cfg_inf;
 pub mod cfg_itfm;
+pub mod cfg_logfilter;
 pub mod cfg_msg;
 pub mod cfg_nav5;
 pub mod cfg_navx5;
+pub mod cfg_nmea;
 pub mod cfg_odo;
+pub mod cfg_pm2;
+pub mod cfg_pms;
 pub mod cfg_prt;
 pub mod cfg_rate;
 pub mod cfg_rst;
+pub mod cfg_rxm;
+pub mod cfg_sbas;
 pub mod cfg_smgr;
 pub mod cfg_tmode2;
 pub mod cfg_tmode3;
 pub mod cfg_tp5;
+pub mod cfg_usb;
 pub mod cfg_val;
 
 pub mod esf_alg;
@@ -53,16 +69,28 @@ pub mod inf_test;
 pub mod inf_warning;
 
 pub mod mga_ack;
+pub mod mga_ano;
+pub mod mga_bds_alm;
 pub mod mga_bds_eph;
+pub mod mga_bds_health;
 pub mod mga_bds_iono;
 pub mod mga_bds_utc;
+pub mod mga_dbd;
+pub mod mga_flash;
+pub mod mga_gal_alm;
 pub mod mga_gal_eph;
 pub mod mga_gal_time;
+pub mod mga_glo_alm;
 pub mod mga_glo_eph;
+pub mod mga_gps_alm;
 pub mod mga_gps_eph;
+pub mod mga_gps_health;
 pub mod mga_gps_iono;
 pub mod mga_gps_utc;
 pub mod mga_ini;
+pub mod mga_qzss_alm;
+pub mod mga_qzss_eph;
+pub mod mga_qzss_health;
 
 pub mod mon_comms;
 pub mod mon_gnss;
@@ -75,7 +103,9 @@ pub mod mon_patch;
 pub mod mon_rf;
 pub mod mon_rxbuf;
 pub mod mon_rxr;
+pub mod mon_smgr;
 pub mod mon_span;
+pub mod mon_sys;
 pub mod mon_txbuf;
 pub mod mon_ver;
 
@@ -83,6 +113,8 @@ pub mod nav_att;
 pub mod nav_clock;
 pub mod nav_cov;
 pub mod nav_dop;
+pub mod nav_eell;
+pub mod nav_geofence;
 pub mod nav_hp_pos_ecef;
 pub mod nav_hp_pos_llh;
 pub mod nav_orb;
@@ -90,31 +122,53 @@ pub mod nav_other;
 pub mod nav_pl;
 pub mod nav_pos_ecef;
 pub mod nav_pos_llh;
+pub mod nav_pvat;
 pub mod nav_pvt;
 pub mod nav_rel_pos_ned;
 pub mod nav_sat;
+pub mod nav_sbas;
 pub mod nav_sig;
+pub mod nav_slas;
 pub mod nav_sol;
 pub mod nav_status;
+pub mod nav_svin;
+pub mod nav_time_bds;
+pub mod nav_time_gal;
+pub mod nav_time_glo;
 pub mod nav_time_gps;
 pub mod nav_time_ls;
+pub mod nav_time_navic;
+pub mod nav_time_qzss;
 pub mod nav_time_utc;
 pub mod nav_vel_ned;
 
 pub mod rxm_cor;
+pub mod rxm_measx;
+pub mod rxm_pmp;
 pub mod rxm_pmreq;
+pub mod rxm_qzssl6;
 pub mod rxm_rawx;
+pub mod rxm_rlm;
 pub mod rxm_rtcm;
 pub mod rxm_sfrbx;
+pub mod rxm_spartn;
+pub mod rxm_spartnkey;
 
+pub mod sec_ecsign;
 pub mod sec_sig;
 pub mod sec_siglog;
+pub mod sec_sign;
 pub mod sec_uniq_id;
 
+pub mod tim_fchg;
+pub mod tim_hoc;
+pub mod tim_smeas;
 pub mod tim_svin;
 pub mod tim_tm2;
 pub mod tim_tos;
 pub mod tim_tp;
+pub mod tim_vcocal;
+pub mod tim_vrfy;
 
 pub mod proto14_packets;
 
@@ -225,6 +279,18 @@ pub enum UtcStandardIdentifier {
     UtcChina = 7,
 }
 
+/// Oscillator selection for timing receivers
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OscillatorId {
+    #[default]
+    Internal = 0,
+    External = 1,
+}
+
 /// GNSS fix Type
 #[ubx_extend]
 #[ubx(from, rest_reserved)]
@@ -0,0 +1,128 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_packet_recv, ubx_packet_recv_send, ubx_packet_send};
+
+/// GPS almanac of a single SV
+///
+/// Output for every SV when polled with `UbxPacketRequest::request_for::<AidAlm>()`,
+/// or for one SV with [AidAlmPollSv], and accepted as input to aid the receiver.
+/// SVs without a valid almanac are reported with [AidAlmEmpty] instead.
+///
+/// Supported on: u-blox 7 and u-blox M8 up to protocol version 23.01.
+/// All UBX-AID messages are deprecated in newer protocol versions; use UBX-MGA messages instead
+#[ubx_packet_recv_send]
+#[ubx(class = 0x0B, id = 0x30, fixed_payload_len = 40)]
+struct AidAlm {
+    /// GPS SV ID (1..32)
+    svid: u32,
+
+    /// Almanac reference week number, truncated to 8 bits in the navigation message
+    week: u32,
+
+    /// Words 3 to 10 of subframe 4/5 carrying the almanac, see [AidAlmRef::words]
+    dwrd: [u8; 32],
+}
+
+/// SV for which the receiver has no valid almanac
+#[ubx_packet_recv]
+#[ubx(class = 0x0B, id = 0x30, fixed_payload_len = 8)]
+struct AidAlmEmpty {
+    /// GPS SV ID (1..32)
+    svid: u32,
+
+    /// Always 0, the almanac is not available
+    week: u32,
+}
+
+/// Poll the GPS almanac of a single SV
+#[ubx_packet_send]
+#[ubx(class = 0x0B, id = 0x30, fixed_payload_len = 1)]
+struct AidAlmPollSv {
+    /// GPS SV ID (1..32)
+    svid: u8,
+}
+
+/// Converts GPS subframe words, each holding 24 data bits without parity, from their
+/// little endian representation
+pub(crate) fn gps_words<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    let mut words = [0; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    words
+}
+
+/// Writes GPS subframe words in their little endian representation
+pub(crate) fn gps_words_to_bytes(words: &[u32], bytes: &mut [u8]) {
+    for (word, chunk) in words.iter().zip(bytes.chunks_exact_mut(4)) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+macro_rules! impl_aid_alm {
+    ($type:ty) => {
+        impl $type {
+            /// Words 3 to 10 of the almanac subframe, bits 0 to 23 hold the
+            /// data bits of the navigation message without parity
+            pub fn words(&self) -> [u32; 8] {
+                gps_words(&self.dwrd())
+            }
+        }
+    };
+}
+
+impl_aid_alm!(AidAlmRef<'_>);
+impl_aid_alm!(AidAlmOwned);
+
+impl AidAlmBuilder {
+    /// Almanac of `svid` for the given reference `week` and subframe `words`
+    pub fn new(svid: u32, week: u32, words: [u32; 8]) -> Self {
+        let mut dwrd = [0; 32];
+        gps_words_to_bytes(&words, &mut dwrd);
+        Self { svid, week, dwrd }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let words = [
+            0x0054_2a00,
+            0x0012_3456,
+            0x00fd_4e00,
+            0x00a1_0d57,
+            0x0087_0d72,
+            0x0034_5678,
+            0x0001_0203,
+            0x00ff_ff00,
+        ];
+        let bytes = AidAlmBuilder::new(17, 0x9b, words).into_packet_bytes();
+        assert_eq!(&bytes[..6], &[0xb5, 0x62, 0x0b, 0x30, 40, 0]);
+        let payload = &bytes[6..46];
+        assert_eq!(
+            &payload[..12],
+            &[17, 0, 0, 0, 0x9b, 0, 0, 0, 0x00, 0x2a, 0x54, 0x00]
+        );
+
+        let packet = AidAlmRef(payload);
+        assert_eq!(packet.svid(), 17);
+        assert_eq!(packet.week(), 0x9b);
+        assert_eq!(packet.words(), words);
+
+        let empty = [17, 0, 0, 0, 0, 0, 0, 0];
+        assert!(AidAlmRef::validate(&empty).is_err());
+        assert!(AidAlmEmptyRef::validate(&empty).is_ok());
+
+        let poll = AidAlmPollSvBuilder { svid: 17 }.into_packet_bytes();
+        assert_eq!(&poll[..7], &[0xb5, 0x62, 0x0b, 0x30, 1, 0, 17]);
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[allow(unused_imports, reason = "It is only unused in some feature sets")]
+use crate::FieldIter;
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend, ubx_packet_recv, ubx_packet_send};
+
+/// Largest amount of data in a single `AidAlpData` message
+pub const AID_ALP_DATA_MAX_LEN: usize = 700;
+
+/// Block of AlmanacPlus data
+///
+/// The ALP file is transferred in blocks, each one acknowledged with
+/// [AidAlpAck] before the next is sent, and the transfer is ended with
+/// [AidAlpStop]. Use [AidAlpDataBuilder::blocks] to split the file.
+///
+/// Supported on: u-blox 7 and u-blox M8 up to protocol version 23.01.
+/// All UBX-AID messages are deprecated in newer protocol versions; use UBX-MGA messages instead
+#[ubx_packet_send]
+#[ubx(class = 0x0B, id = 0x50, max_payload_len = 700)]
+struct AidAlpData<'a> {
+    /// ALP file data, the length must be a multiple of 2
+    #[ubx(map_type = &'a [u8], into = AidAlpData::data_into_bytes)]
+    data: [u8; 0],
+}
+
+impl AidAlpData {
+    fn data_into_bytes(bytes: &[u8]) -> &[u8] {
+        bytes
+    }
+}
+
+impl<'a> AidAlpDataBuilder<'a> {
+    /// Splits the ALP file `data` into blocks, in the order they must be sent
+    pub fn blocks(data: &'a [u8]) -> impl Iterator<Item = Self> + 'a {
+        debug_assert!(data.len().is_multiple_of(2));
+        data.chunks(AID_ALP_DATA_MAX_LEN).map(|data| Self { data })
+    }
+}
+
+/// End of the transfer of AlmanacPlus data
+#[ubx_packet_send]
+#[ubx(
+    class = 0x0B,
+    id = 0x50,
+    fixed_payload_len = 1,
+    flags = "default_for_builder"
+)]
+struct AidAlpStop {
+    /// Any value
+    dummy: u8,
+}
+
+/// Acknowledgment of an [AidAlpData] block
+#[ubx_packet_recv]
+#[ubx(class = 0x0B, id = 0x50, fixed_payload_len = 1)]
+struct AidAlpAck {
+    /// See [AidAlpAckType]
+    #[ubx(map_type = AidAlpAckType)]
+    ack_type: u8,
+}
+
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AidAlpAckType {
+    /// The block was not accepted
+    Nak = 0,
+    /// The block was accepted
+    Ack = 1,
+}
+
+/// Status of the AlmanacPlus data held by the receiver
+///
+/// Output when polled with `UbxPacketRequest::request_for::<AidAlpStatus>()`.
+#[ubx_packet_recv]
+#[ubx(class = 0x0B, id = 0x50, fixed_payload_len = 24)]
+struct AidAlpStatus {
+    /// Start of prediction, GPS time of week (s)
+    pred_tow: u32,
+
+    /// Prediction duration (s)
+    pred_dur: u32,
+
+    /// Current age of the ALP data (s)
+    age: i32,
+
+    /// Start of prediction, GPS week number
+    pred_wno: u16,
+
+    /// Almanac week number, truncated to 8 bits in the navigation message
+    alm_wno: u16,
+
+    reserved1: [u8; 4],
+
+    /// Number of satellites in the ALP data
+    svs: u8,
+
+    reserved2: [u8; 3],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn data_blocks() {
+        let data = [0x5a; 1000];
+        let blocks: alloc::vec::Vec<_> = AidAlpDataBuilder::blocks(&data)
+            .map(|b| b.into_packet_vec())
+            .collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(
+            &blocks[0][..8],
+            &[0xb5, 0x62, 0x0b, 0x50, 0xbc, 0x02, 0x5a, 0x5a]
+        );
+        assert_eq!(blocks[0].len(), 8 + 700);
+        assert_eq!(
+            &blocks[1][..8],
+            &[0xb5, 0x62, 0x0b, 0x50, 0x2c, 0x01, 0x5a, 0x5a]
+        );
+        assert_eq!(blocks[1].len(), 8 + 300);
+    }
+
+    #[test]
+    fn ack_and_status() {
+        assert!(AidAlpAckRef::validate(&[0x01]).is_ok());
+        assert_eq!(AidAlpAckRef(&[0x01]).ack_type(), AidAlpAckType::Ack);
+        assert_eq!(AidAlpAckRef(&[0x00]).ack_type(), AidAlpAckType::Nak);
+
+        let payload = [
+            0x80, 0x51, 0x01, 0x00, // pred_tow
+            0x80, 0x3a, 0x09, 0x00, // pred_dur
+            0x10, 0x0e, 0x00, 0x00, // age
+            0x4e, 0x08, // pred_wno
+            0x4e, 0x00, // alm_wno
+            0x00, 0x00, 0x00, 0x00, // reserved1
+            0x1f, // svs
+            0x00, 0x00, 0x00, // reserved2
+        ];
+        assert!(AidAlpStatusRef::validate(&payload).is_ok());
+        let packet = AidAlpStatusRef(&payload);
+        assert_eq!(packet.pred_tow(), 86_400);
+        assert_eq!(packet.pred_dur(), 604_800);
+        assert_eq!(packet.age(), 3600);
+        assert_eq!(packet.pred_wno(), 2126);
+        assert_eq!(packet.svs(), 31);
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_packet_recv_send, ubx_packet_send};
+
+/// AssistNow Autonomous data of a single GPS SV
+///
+/// Output for every SV when polled with `UbxPacketRequest::request_for::<AidAop>()`,
+/// or for one SV with [AidAopPollSv]. Sending the data back restores the orbit
+/// predictions, for example after a cold start.
+///
+/// Supported on: u-blox 7 and u-blox M8 up to protocol version 23.01.
+/// All UBX-AID messages are deprecated in newer protocol versions; use UBX-MGA messages instead
+#[ubx_packet_recv_send]
+#[ubx(class = 0x0B, id = 0x33, fixed_payload_len = 60)]
+struct AidAop {
+    /// GPS SV ID (1..32)
+    svid: u8,
+
+    /// AssistNow Autonomous data, not documented by u-blox
+    data: [u8; 59],
+}
+
+/// Poll the AssistNow Autonomous data of a single GPS SV
+#[ubx_packet_send]
+#[ubx(class = 0x0B, id = 0x33, fixed_payload_len = 1)]
+struct AidAopPollSv {
+    /// GPS SV ID (1..32)
+    svid: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut payload = [0xa5; 60];
+        payload[0] = 12;
+        assert!(AidAopRef::validate(&payload).is_ok());
+        let packet = AidAopRef(&payload);
+        assert_eq!(packet.svid(), 12);
+
+        let bytes = AidAopBuilder {
+            svid: packet.svid(),
+            data: packet.data(),
+        }
+        .into_packet_bytes();
+        assert_eq!(&bytes[..6], &[0xb5, 0x62, 0x0b, 0x33, 60, 0]);
+        assert_eq!(&bytes[6..66], &payload[..]);
+
+        let poll = AidAopPollSvBuilder { svid: 12 }.into_packet_bytes();
+        assert_eq!(&poll[..7], &[0xb5, 0x62, 0x0b, 0x33, 1, 0, 12]);
+    }
+}
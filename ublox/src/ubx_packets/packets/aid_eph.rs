@@ -0,0 +1,124 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use super::aid_alm::{gps_words, gps_words_to_bytes};
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_packet_recv, ubx_packet_recv_send, ubx_packet_send};
+
+/// GPS ephemeris of a single SV
+///
+/// Output for every SV when polled with `UbxPacketRequest::request_for::<AidEph>()`,
+/// or for one SV with [AidEphPollSv], and accepted as input to aid the receiver.
+/// SVs without a valid ephemeris are reported with [AidEphEmpty] instead.
+///
+/// Supported on: u-blox 7 and u-blox M8 up to protocol version 23.01.
+/// All UBX-AID messages are deprecated in newer protocol versions; use UBX-MGA messages instead
+#[ubx_packet_recv_send]
+#[ubx(class = 0x0B, id = 0x31, fixed_payload_len = 104)]
+struct AidEph {
+    /// GPS SV ID (1..32)
+    svid: u32,
+
+    /// Hand-over word of the first subframe, 0 when no ephemeris is available
+    how: u32,
+
+    /// Words 3 to 10 of subframe 1, see [AidEphRef::subframe_words]
+    sf1d: [u8; 32],
+
+    /// Words 3 to 10 of subframe 2, see [AidEphRef::subframe_words]
+    sf2d: [u8; 32],
+
+    /// Words 3 to 10 of subframe 3, see [AidEphRef::subframe_words]
+    sf3d: [u8; 32],
+}
+
+/// SV for which the receiver has no valid ephemeris
+#[ubx_packet_recv]
+#[ubx(class = 0x0B, id = 0x31, fixed_payload_len = 8)]
+struct AidEphEmpty {
+    /// GPS SV ID (1..32)
+    svid: u32,
+
+    /// Always 0, the ephemeris is not available
+    how: u32,
+}
+
+/// Poll the GPS ephemeris of a single SV
+#[ubx_packet_send]
+#[ubx(class = 0x0B, id = 0x31, fixed_payload_len = 1)]
+struct AidEphPollSv {
+    /// GPS SV ID (1..32)
+    svid: u8,
+}
+
+macro_rules! impl_aid_eph {
+    ($type:ty) => {
+        impl $type {
+            /// Words 3 to 10 of `subframe` (1, 2 or 3), bits 0 to 23 hold the
+            /// data bits of the navigation message without parity
+            pub fn subframe_words(&self, subframe: usize) -> Option<[u32; 8]> {
+                match subframe {
+                    1 => Some(gps_words(&self.sf1d())),
+                    2 => Some(gps_words(&self.sf2d())),
+                    3 => Some(gps_words(&self.sf3d())),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_aid_eph!(AidEphRef<'_>);
+impl_aid_eph!(AidEphOwned);
+
+impl AidEphBuilder {
+    /// Ephemeris of `svid` with its hand-over word and the words of subframes 1 to 3
+    pub fn new(svid: u32, how: u32, subframes: [[u32; 8]; 3]) -> Self {
+        let mut sfd = [[0; 32]; 3];
+        for (bytes, words) in sfd.iter_mut().zip(subframes.iter()) {
+            gps_words_to_bytes(words, bytes);
+        }
+        let [sf1d, sf2d, sf3d] = sfd;
+        Self {
+            svid,
+            how,
+            sf1d,
+            sf2d,
+            sf3d,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut subframes = [[0; 8]; 3];
+        for (i, words) in subframes.iter_mut().enumerate() {
+            for (j, word) in words.iter_mut().enumerate() {
+                *word = ((i as u32 + 1) << 16) | j as u32;
+            }
+        }
+        let bytes = AidEphBuilder::new(5, 0x0012_3400, subframes).into_packet_bytes();
+        assert_eq!(&bytes[..6], &[0xb5, 0x62, 0x0b, 0x31, 104, 0]);
+        let payload = &bytes[6..110];
+        assert_eq!(&payload[..8], &[5, 0, 0, 0, 0x00, 0x34, 0x12, 0x00]);
+        assert_eq!(&payload[40..44], &[0x00, 0x00, 0x02, 0x00]);
+
+        let packet = AidEphRef(payload);
+        assert_eq!(packet.svid(), 5);
+        assert_eq!(packet.how(), 0x0012_3400);
+        assert_eq!(packet.subframe_words(1), Some(subframes[0]));
+        assert_eq!(packet.subframe_words(2), Some(subframes[1]));
+        assert_eq!(packet.subframe_words(3), Some(subframes[2]));
+        assert_eq!(packet.subframe_words(4), None);
+
+        assert!(AidEphEmptyRef::validate(&[5, 0, 0, 0, 0, 0, 0, 0]).is_ok());
+    }
+}
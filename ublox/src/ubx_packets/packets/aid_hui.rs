@@ -0,0 +1,150 @@
+use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv_send};
+
+/// GPS health, UTC and ionosphere parameters
+///
+/// Output when polled with `UbxPacketRequest::request_for::<AidHui>()`, and
+/// accepted as input to aid the receiver.
+///
+/// Supported on: u-blox 7 and u-blox M8 up to protocol version 23.01.
+/// All UBX-AID messages are deprecated in newer protocol versions; use UBX-MGA messages instead
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x0B,
+    id = 0x02,
+    fixed_payload_len = 72,
+    flags = "default_for_builder"
+)]
+struct AidHui {
+    /// Healthy SVs, bit `n - 1` is set when SV `n` is healthy, see [AidHuiRef::is_healthy]
+    health: u32,
+
+    /// UTC - parameter A0
+    utc_a0: f64,
+
+    /// UTC - parameter A1
+    utc_a1: f64,
+
+    /// UTC - reference time of week
+    utc_tow: i32,
+
+    /// UTC - reference week number
+    utc_wnt: i16,
+
+    /// UTC - time difference due to leap seconds before event
+    utc_ls: i16,
+
+    /// UTC - week number when next leap second event occurs
+    utc_wnf: i16,
+
+    /// UTC - day of week when next leap second event occurs
+    utc_dn: i16,
+
+    /// UTC - time difference due to leap seconds after event
+    utc_lsf: i16,
+
+    /// UTC - spare to ensure structure is a multiple of 4 bytes
+    utc_spare: i16,
+
+    /// Klobuchar - alpha 0 (s)
+    klob_a0: f32,
+
+    /// Klobuchar - alpha 1 (s/semicircle)
+    klob_a1: f32,
+
+    /// Klobuchar - alpha 2 (s/semicircle^2)
+    klob_a2: f32,
+
+    /// Klobuchar - alpha 3 (s/semicircle^3)
+    klob_a3: f32,
+
+    /// Klobuchar - beta 0 (s)
+    klob_b0: f32,
+
+    /// Klobuchar - beta 1 (s/semicircle)
+    klob_b1: f32,
+
+    /// Klobuchar - beta 2 (s/semicircle^2)
+    klob_b2: f32,
+
+    /// Klobuchar - beta 3 (s/semicircle^3)
+    klob_b3: f32,
+
+    /// Valid parameter groups, see [AidHuiFlags]
+    #[ubx(map_type = AidHuiFlags)]
+    flags: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// Valid parameter groups of [AidHui]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct AidHuiFlags: u32 {
+        /// Health bitmask is valid
+        const HEALTH_VALID = 0x01;
+        /// UTC parameters are valid
+        const UTC_VALID = 0x02;
+        /// Klobuchar parameters are valid
+        const KLOB_VALID = 0x04;
+    }
+}
+
+macro_rules! impl_aid_hui {
+    ($type:ty) => {
+        impl $type {
+            /// Whether GPS SV `svid` (1..32) is healthy, `None` when the SV ID
+            /// is out of range or the health bitmask is not valid
+            pub fn is_healthy(&self, svid: u8) -> Option<bool> {
+                if !(1..=32).contains(&svid) || !self.flags().contains(AidHuiFlags::HEALTH_VALID) {
+                    return None;
+                }
+                Some(self.health() & (1 << (svid - 1)) != 0)
+            }
+        }
+    };
+}
+
+impl_aid_hui!(AidHuiRef<'_>);
+impl_aid_hui!(AidHuiOwned);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes = AidHuiBuilder {
+            health: 0xffff_fffb,
+            utc_a0: -9.313225746154785e-10,
+            utc_ls: 18,
+            utc_lsf: 18,
+            klob_a0: 1.5e-8,
+            klob_b3: -65536.0,
+            flags: AidHuiFlags::HEALTH_VALID | AidHuiFlags::KLOB_VALID,
+            ..Default::default()
+        }
+        .into_packet_bytes();
+        assert_eq!(&bytes[..6], &[0xb5, 0x62, 0x0b, 0x02, 72, 0]);
+        let payload = &bytes[6..78];
+        assert_eq!(&payload[68..], &[0x05, 0x00, 0x00, 0x00]);
+
+        let packet = AidHuiRef(payload);
+        assert_eq!(packet.utc_a0(), -9.313225746154785e-10);
+        assert_eq!(packet.utc_ls(), 18);
+        assert_eq!(packet.klob_a0(), 1.5e-8);
+        assert_eq!(packet.klob_b3(), -65536.0);
+        assert_eq!(packet.is_healthy(1), Some(true));
+        assert_eq!(packet.is_healthy(3), Some(false));
+        assert_eq!(packet.is_healthy(33), None);
+    }
+}
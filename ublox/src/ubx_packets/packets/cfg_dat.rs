@@ -0,0 +1,119 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use super::mon_ver::fixed_size_str;
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_packet_recv, ubx_packet_send};
+
+/// Currently used datum, as reported when polling
+#[ubx_packet_recv]
+#[ubx(class = 0x06, id = 0x06, fixed_payload_len = 52)]
+struct CfgDat {
+    /// Datum number, 0 = WGS84, 0xFFFF = user-defined
+    datum_num: u16,
+
+    /// Datum name, `None` if it is not valid ASCII
+    #[ubx(map_type = Option<&str>, from = fixed_size_str, get_as_ref)]
+    datum_name: [u8; 6],
+
+    /// Semi-major axis (m)
+    maj_a: f64,
+
+    /// 1.0 / flattening
+    flat: f64,
+
+    /// X axis shift at the origin (m)
+    d_x: f32,
+
+    /// Y axis shift at the origin (m)
+    d_y: f32,
+
+    /// Z axis shift at the origin (m)
+    d_z: f32,
+
+    /// Rotation about the X axis (s)
+    rot_x: f32,
+
+    /// Rotation about the Y axis (s)
+    rot_y: f32,
+
+    /// Rotation about the Z axis (s)
+    rot_z: f32,
+
+    /// Scale change (ppm)
+    scale: f32,
+}
+
+/// Set user-defined datum
+///
+/// The datum in use can be polled with `CfgDat`.
+#[ubx_packet_send]
+#[ubx(class = 0x06, id = 0x06, fixed_payload_len = 44)]
+struct CfgDatSet {
+    /// Semi-major axis (m), accepted range is 6,300,000 to 6,500,000
+    maj_a: f64,
+
+    /// 1.0 / flattening, accepted range is 0 to 500
+    flat: f64,
+
+    /// X axis shift at the origin (m), accepted range is +/- 5000
+    d_x: f32,
+
+    /// Y axis shift at the origin (m), accepted range is +/- 5000
+    d_y: f32,
+
+    /// Z axis shift at the origin (m), accepted range is +/- 5000
+    d_z: f32,
+
+    /// Rotation about the X axis (s), accepted range is +/- 20
+    rot_x: f32,
+
+    /// Rotation about the Y axis (s), accepted range is +/- 20
+    rot_y: f32,
+
+    /// Rotation about the Z axis (s), accepted range is +/- 20
+    rot_z: f32,
+
+    /// Scale change (ppm), accepted range is 0 to 50
+    scale: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes = CfgDatSetBuilder {
+            maj_a: 6_378_137.0,
+            flat: 298.257_223_563,
+            d_x: -87.0,
+            d_y: -98.0,
+            d_z: -121.0,
+            rot_x: 0.0,
+            rot_y: 0.0,
+            rot_z: 0.0,
+            scale: 0.0,
+        }
+        .into_packet_bytes();
+
+        // A poll answer carries the datum number and name in front of the parameters
+        let mut payload = [0u8; 52];
+        payload[..2].copy_from_slice(&0xffffu16.to_le_bytes());
+        payload[2..8].copy_from_slice(b"USER\0\0");
+        payload[8..].copy_from_slice(&bytes[6..50]);
+        assert!(CfgDatRef::validate(&payload).is_ok());
+
+        let packet = CfgDatRef(&payload);
+        assert_eq!(packet.datum_num(), 0xffff);
+        assert_eq!(packet.datum_name(), Some("USER"));
+        assert_eq!(packet.maj_a(), 6_378_137.0);
+        assert_eq!(packet.flat(), 298.257_223_563);
+        assert_eq!(packet.d_x(), -87.0);
+        assert_eq!(packet.d_z(), -121.0);
+    }
+}
@@ -0,0 +1,65 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend, ubx_packet_recv_send};
+
+/// DGNSS configuration
+///
+/// Only available on high precision receivers
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x70,
+    fixed_payload_len = 4,
+    flags = "default_for_builder"
+)]
+struct CfgDgnss {
+    /// RTK mode, see [CfgDgnssMode]
+    #[ubx(map_type = CfgDgnssMode, may_fail)]
+    dgnss_mode: u8,
+
+    reserved1: [u8; 3],
+}
+
+/// RTK mode
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgDgnssMode {
+    /// No attempts are made to fix ambiguities
+    RtkFloat = 2,
+    /// Ambiguities are fixed whenever possible
+    #[default]
+    RtkFixed = 3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes = CfgDgnssBuilder {
+            dgnss_mode: CfgDgnssMode::RtkFloat,
+            ..Default::default()
+        }
+        .into_packet_bytes();
+        assert_eq!(
+            bytes,
+            [0xb5, 0x62, 0x06, 0x70, 0x04, 0x00, 0x02, 0x00, 0x00, 0x00, 0x7c, 0x60]
+        );
+        assert!(CfgDgnssRef::validate(&bytes[6..10]).is_ok());
+        assert_eq!(
+            CfgDgnssRef(&bytes[6..10]).dgnss_mode(),
+            CfgDgnssMode::RtkFloat
+        );
+        assert!(CfgDgnssRef::validate(&[0x01, 0x00, 0x00, 0x00]).is_err());
+    }
+}
@@ -0,0 +1,321 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[allow(unused_imports, reason = "It's only unused in some feature sets")]
+use crate::FieldIter;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::fmt;
+
+use crate::{
+    error::ParserError, ubx_checksum, ubx_packets::packets::ScaleBack, PositionLLA, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend, ubx_packet_recv_send};
+
+/// Geofencing configuration
+///
+/// Up to [CfgGeofence::MAX_FENCES] circular geofences can be configured. The combined
+/// state of all fences can be output on a PIO pin and is reported in UBX-NAV-GEOFENCE.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use ublox::{PositionLLA, cfg_geofence::{CfgGeofenceBuilder, Geofence, GeofenceConfidence}};
+/// let fences = [
+///     Geofence::new(PositionLLA { lat: 47.2850, lon: 8.5650, alt: 0.0 }, 150.0),
+///     Geofence::new(PositionLLA { lat: 47.2900, lon: 8.5700, alt: 0.0 }, 50.0),
+/// ];
+///
+/// let mut buffer = Vec::new();
+/// CfgGeofenceBuilder {
+///     conf_lvl: GeofenceConfidence::Sigma3,
+///     ..Default::default()
+/// }
+/// .with_fences(&fences)
+/// .extend_to(&mut buffer);
+/// ```
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x69,
+    max_payload_len = 56, // 8 + 12 * MAX_FENCES
+    flags = "default_for_builder"
+)]
+struct CfgGeofence<'a> {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Number of geofences contained in this message.
+    /// Note that the receiver can only store a limited number of geofences (currently 4).
+    num_fences: u8,
+
+    /// Required confidence level for state evaluation, see [GeofenceConfidence]
+    #[ubx(map_type = GeofenceConfidence, may_fail)]
+    conf_lvl: u8,
+
+    reserved1: u8,
+
+    /// Enable PIO combined fence state output
+    #[ubx(map_type = bool, from = pio_enabled_from_raw, into = pio_enabled_into_raw)]
+    pio_enabled: u8,
+
+    /// PIO pin polarity, see [GeofencePinPolarity]
+    #[ubx(map_type = GeofencePinPolarity, may_fail)]
+    pin_polarity: u8,
+
+    /// PIO pin number
+    pin: u8,
+
+    reserved2: u8,
+
+    #[ubx(
+        map_type = GeofenceIter<'a>,
+        from = GeofenceIter::new,
+        is_valid = GeofenceIter::is_valid,
+        may_fail,
+    )]
+    fences: [u8; 0],
+}
+
+impl CfgGeofence {
+    /// Maximum number of geofences the receiver can store
+    pub const MAX_FENCES: usize = 4;
+}
+
+fn pio_enabled_from_raw(raw: u8) -> bool {
+    raw != 0
+}
+
+fn pio_enabled_into_raw(enabled: bool) -> u8 {
+    u8::from(enabled)
+}
+
+/// Confidence level required before a geofence state is reported
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeofenceConfidence {
+    /// No confidence required
+    #[default]
+    None = 0,
+    /// 68%
+    Sigma1 = 1,
+    /// 95%
+    Sigma2 = 2,
+    /// 99.7%
+    Sigma3 = 3,
+    /// 99.99%
+    Sigma4 = 4,
+    /// 99.9999%
+    Sigma5 = 5,
+}
+
+/// Polarity of the PIO pin used for the combined geofence state
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from_unchecked, into_raw, rest_error)]
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GeofencePinPolarity {
+    /// Low means inside
+    #[default]
+    LowMeansInside = 0,
+    /// Low means outside
+    LowMeansOutside = 1,
+}
+
+/// A single circular geofence
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Geofence {
+    /// Latitude of the geofence circle center (deg)
+    pub lat: f64,
+    /// Longitude of the geofence circle center (deg)
+    pub lon: f64,
+    /// Radius of the geofence circle (m)
+    pub radius: f64,
+}
+
+impl Geofence {
+    const BLOCK_SIZE: usize = 12;
+
+    /// Creates a geofence centered on `center` (altitude is ignored)
+    /// with a `radius` in meters.
+    pub fn new(center: PositionLLA, radius: f64) -> Self {
+        Self {
+            lat: center.lat,
+            lon: center.lon,
+            radius,
+        }
+    }
+
+    /// Center of the geofence circle, with altitude set to 0
+    pub fn center(&self) -> PositionLLA {
+        PositionLLA {
+            lon: self.lon,
+            lat: self.lat,
+            alt: 0.0,
+        }
+    }
+
+    fn from_bytes(chunk: &[u8]) -> Self {
+        let lat = i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        let lon = i32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+        let radius = u32::from_le_bytes([chunk[8], chunk[9], chunk[10], chunk[11]]);
+        Self {
+            lat: f64::from(lat) * 1e-7,
+            lon: f64::from(lon) * 1e-7,
+            radius: f64::from(radius) * 1e-2,
+        }
+    }
+
+    pub fn extend_to<T>(&self, buf: &mut T) -> usize
+    where
+        T: core::iter::Extend<u8>,
+    {
+        buf.extend(ScaleBack::<f64>(1e7).as_i32(self.lat).to_le_bytes());
+        buf.extend(ScaleBack::<f64>(1e7).as_i32(self.lon).to_le_bytes());
+        buf.extend(ScaleBack::<f64>(1e2).as_u32(self.radius).to_le_bytes());
+        Self::BLOCK_SIZE
+    }
+}
+
+#[derive(Clone)]
+pub enum GeofenceIter<'a> {
+    // If the packet is of type `recv` we use this variant to iterate over the bytes
+    Bytes(core::slice::ChunksExact<'a, u8>),
+    // If the packet is of type `send` we use this variant to iterate over the slices
+    // of Geofence for CfgGeofenceBuilder and build the entire packet
+    Slice(core::slice::Iter<'a, Geofence>),
+}
+
+impl<'a> GeofenceIter<'a> {
+    fn from_slice(fences: &'a [Geofence]) -> Self {
+        Self::Slice(fences.iter())
+    }
+
+    // For internal use by recv variant
+    fn new(bytes: &'a [u8]) -> Self {
+        Self::Bytes(bytes.chunks_exact(Geofence::BLOCK_SIZE))
+    }
+
+    #[allow(dead_code, reason = "Used by ubx_packet_recv macro for validation")]
+    fn is_valid(bytes: &'a [u8]) -> bool {
+        bytes.len().is_multiple_of(Geofence::BLOCK_SIZE)
+    }
+}
+
+impl Default for GeofenceIter<'_> {
+    fn default() -> Self {
+        Self::from_slice(&[])
+    }
+}
+
+impl fmt::Debug for GeofenceIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl core::iter::Iterator for GeofenceIter<'_> {
+    type Item = Geofence;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Bytes(chunks) => chunks.next().map(Geofence::from_bytes),
+            Self::Slice(it) => it.next().copied(),
+        }
+    }
+}
+
+/// Convenience method to set the fences for the builder
+impl<'a> CfgGeofenceBuilder<'a> {
+    /// Sets the geofences to configure. The receiver cannot store more than
+    /// [CfgGeofence::MAX_FENCES] fences, only the first ones are configured.
+    pub fn with_fences(mut self, fences: &'a [Geofence]) -> Self {
+        let fences = &fences[..fences.len().min(CfgGeofence::MAX_FENCES)];
+        self.num_fences = fences.len() as u8;
+        self.fences = GeofenceIter::from_slice(fences);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports, reason = "unused in some feature sets")]
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn serialize_and_parse() {
+        let fences = [
+            Geofence::new(
+                PositionLLA {
+                    lat: 47.285_012_3,
+                    lon: 8.565_098_7,
+                    alt: 420.0,
+                },
+                150.25,
+            ),
+            Geofence::new(
+                PositionLLA {
+                    lat: -33.856_784_4,
+                    lon: 151.215_296_7,
+                    alt: 0.0,
+                },
+                12.0,
+            ),
+        ];
+
+        let mut out = Vec::new();
+        CfgGeofenceBuilder {
+            conf_lvl: GeofenceConfidence::Sigma3,
+            pio_enabled: true,
+            pin_polarity: GeofencePinPolarity::LowMeansOutside,
+            pin: 3,
+            ..Default::default()
+        }
+        .with_fences(&fences)
+        .extend_to(&mut out);
+
+        const HEADER_LEN: usize = 6;
+        let len = u16::from_le_bytes([out[4], out[5]]) as usize;
+        assert_eq!(len, 8 + 2 * 12);
+        let payload = &out[HEADER_LEN..HEADER_LEN + len];
+        assert!(CfgGeofenceRef::validate(payload).is_ok());
+
+        let r = CfgGeofenceRef(payload);
+        assert_eq!(r.num_fences(), 2);
+        assert_eq!(r.conf_lvl(), GeofenceConfidence::Sigma3);
+        assert!(r.pio_enabled());
+        assert_eq!(r.pin_polarity(), GeofencePinPolarity::LowMeansOutside);
+        assert_eq!(r.pin(), 3);
+
+        let parsed: Vec<Geofence> = r.fences().collect();
+        assert_eq!(parsed.len(), 2);
+        for (parsed, expected) in parsed.iter().zip(fences.iter()) {
+            assert!((parsed.lat - expected.lat).abs() < 1e-7);
+            assert!((parsed.lon - expected.lon).abs() < 1e-7);
+            assert!((parsed.radius - expected.radius).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn too_many_fences() {
+        let fence = Geofence::new(
+            PositionLLA {
+                lat: 47.285,
+                lon: 8.565,
+                alt: 0.0,
+            },
+            10.0,
+        );
+        let fences = [fence; 6];
+        let builder = CfgGeofenceBuilder::default().with_fences(&fences);
+        assert_eq!(builder.num_fences as usize, CfgGeofence::MAX_FENCES);
+    }
+}
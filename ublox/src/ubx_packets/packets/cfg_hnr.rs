@@ -0,0 +1,45 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::ubx_packet_recv_send;
+
+/// High navigation rate settings
+///
+/// Only available on ADR and UDR products
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x5c,
+    fixed_payload_len = 4,
+    flags = "default_for_builder"
+)]
+struct CfgHnr {
+    /// Rate of navigation solution output (Hz)
+    high_nav_rate: u8,
+
+    reserved1: [u8; 3],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes = CfgHnrBuilder {
+            high_nav_rate: 30,
+            ..Default::default()
+        }
+        .into_packet_bytes();
+        assert_eq!(
+            bytes,
+            [0xb5, 0x62, 0x06, 0x5c, 0x04, 0x00, 0x1e, 0x00, 0x00, 0x00, 0x84, 0x44]
+        );
+        assert_eq!(CfgHnrRef(&bytes[6..10]).high_nav_rate(), 30);
+    }
+}
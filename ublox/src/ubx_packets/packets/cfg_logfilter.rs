@@ -0,0 +1,96 @@
+use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv_send};
+
+/// Data logger configuration
+///
+/// A position is logged when any of the enabled thresholds is exceeded,
+/// a threshold of 0 disables it.
+#[ubx_packet_recv_send]
+#[ubx(class = 0x06, id = 0x47, fixed_payload_len = 12)]
+struct CfgLogfilter {
+    /// Message version (0x01 for this version)
+    version: u8,
+
+    /// Logging flags, see [CfgLogfilterFlags]
+    #[ubx(map_type = CfgLogfilterFlags)]
+    flags: u8,
+
+    /// Minimum time interval between logged positions (s)
+    min_interval: u16,
+
+    /// Time interval threshold (s)
+    time_threshold: u16,
+
+    /// Speed threshold (m/s)
+    speed_threshold: u16,
+
+    /// Position threshold (m)
+    position_threshold: u32,
+}
+
+impl Default for CfgLogfilterBuilder {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            flags: CfgLogfilterFlags::default(),
+            min_interval: 0,
+            time_threshold: 0,
+            speed_threshold: 0,
+            position_threshold: 0,
+        }
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// Data logger flags
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgLogfilterFlags: u8 {
+        /// Enable recording
+        const RECORD_ENABLED = 0x01;
+        /// Log only one position per power save mode wake-up period
+        const PSM_ONCE_PER_WAKE_UP_ENABLED = 0x02;
+        /// Apply all filter settings, otherwise only `RECORD_ENABLED` is applied
+        const APPLY_ALL_FILTER_SETTINGS = 0x04;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes = CfgLogfilterBuilder {
+            flags: CfgLogfilterFlags::RECORD_ENABLED | CfgLogfilterFlags::APPLY_ALL_FILTER_SETTINGS,
+            min_interval: 5,
+            position_threshold: 100,
+            ..Default::default()
+        }
+        .into_packet_bytes();
+        let payload = &bytes[6..18];
+        assert_eq!(
+            payload,
+            [0x01, 0x05, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x64, 0x00, 0x00, 0x00]
+        );
+
+        let packet = CfgLogfilterRef(payload);
+        assert_eq!(packet.version(), 1);
+        assert_eq!(
+            packet.flags(),
+            CfgLogfilterFlags::RECORD_ENABLED | CfgLogfilterFlags::APPLY_ALL_FILTER_SETTINGS
+        );
+        assert_eq!(packet.min_interval(), 5);
+        assert_eq!(packet.position_threshold(), 100);
+    }
+}
@@ -0,0 +1,222 @@
+use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv_send};
+
+/// Extended NMEA protocol configuration (version 0x01, 20 bytes)
+#[ubx_packet_recv_send]
+#[ubx(class = 0x06, id = 0x17, fixed_payload_len = 20)]
+struct CfgNmea {
+    /// Filters applied to the NMEA output, see [CfgNmeaFilter]
+    #[ubx(map_type = CfgNmeaFilter)]
+    filter: u8,
+
+    /// NMEA protocol version, see [NmeaVersion]
+    #[ubx(map_type = NmeaVersion)]
+    nmea_version: u8,
+
+    /// Maximum number of SVs to report per talker ID, 0 = unlimited
+    num_sv: u8,
+
+    /// NMEA flags, see [CfgNmeaFlags]
+    #[ubx(map_type = CfgNmeaFlags)]
+    flags: u8,
+
+    /// GNSS whose satellites are not reported, see [CfgNmeaGnssFilter]
+    #[ubx(map_type = CfgNmeaGnssFilter)]
+    gnss_to_filter: u32,
+
+    /// Satellite numbering, see [NmeaSvNumbering]
+    #[ubx(map_type = NmeaSvNumbering)]
+    sv_numbering: u8,
+
+    /// Talker ID of GNSS independent messages, see [NmeaMainTalkerId]
+    #[ubx(map_type = NmeaMainTalkerId)]
+    main_talker_id: u8,
+
+    /// Talker ID of GSV messages, see [NmeaGsvTalkerId]
+    #[ubx(map_type = NmeaGsvTalkerId)]
+    gsv_talker_id: u8,
+
+    /// Message version (0x01 for this version)
+    version: u8,
+
+    /// BeiDou talker ID characters, 0 for the default "GB"
+    bds_talker_id: [u8; 2],
+
+    reserved1: [u8; 6],
+}
+
+impl Default for CfgNmeaBuilder {
+    fn default() -> Self {
+        Self {
+            filter: CfgNmeaFilter::default(),
+            nmea_version: NmeaVersion::default(),
+            num_sv: 0,
+            flags: CfgNmeaFlags::default(),
+            gnss_to_filter: CfgNmeaGnssFilter::default(),
+            sv_numbering: NmeaSvNumbering::default(),
+            main_talker_id: NmeaMainTalkerId::default(),
+            gsv_talker_id: NmeaGsvTalkerId::default(),
+            version: 1,
+            bds_talker_id: [0; 2],
+            reserved1: [0; 6],
+        }
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// Output of messages without a valid position, time or date
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgNmeaFilter: u8 {
+        /// Output invalid position fixes
+        const POS_FILT = 0x01;
+        /// Output masked position fixes
+        const MSK_POS_FILT = 0x02;
+        /// Output invalid time
+        const TIME_FILT = 0x04;
+        /// Output invalid date
+        const DATE_FILT = 0x08;
+        /// Restrict output to GPS satellites only
+        const GPS_ONLY_FILTER = 0x10;
+        /// Output heading and course over ground even if they are not valid
+        const TRACK_FILT = 0x20;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// NMEA flags
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgNmeaFlags: u8 {
+        /// Compatibility mode, needed for some NMEA parsers
+        const COMPAT = 0x01;
+        /// Considering mode
+        const CONSIDER = 0x02;
+        /// Limit sentence length to 82 characters
+        const LIMIT82 = 0x04;
+        /// High precision mode, not supported in compatibility mode
+        const HIGH_PREC = 0x08;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// GNSS satellites that are not output
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgNmeaGnssFilter: u32 {
+        const GPS = 0x01;
+        const SBAS = 0x02;
+        const GALILEO = 0x04;
+        const QZSS = 0x10;
+        const GLONASS = 0x20;
+        const BEIDOU = 0x40;
+    }
+}
+
+/// NMEA protocol version
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from, into_raw, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NmeaVersion {
+    V21 = 0x21,
+    V23 = 0x23,
+    V40 = 0x40,
+    #[default]
+    V41 = 0x41,
+    V411 = 0x4b,
+}
+
+/// Satellite numbering
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from, into_raw, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NmeaSvNumbering {
+    /// Satellites without an NMEA defined number are not output
+    #[default]
+    Strict = 0,
+    /// Use proprietary numbering for satellites without an NMEA defined number
+    Extended = 1,
+}
+
+/// Main talker ID
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from, into_raw, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NmeaMainTalkerId {
+    /// Talker ID follows the GNSS configuration
+    #[default]
+    NotOverridden = 0,
+    Gp = 1,
+    Gl = 2,
+    Gn = 3,
+    Ga = 4,
+    Gb = 5,
+    Gq = 7,
+}
+
+/// Talker ID of GSV messages
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from, into_raw, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NmeaGsvTalkerId {
+    /// GNSS specific talker ID, as defined by NMEA
+    #[default]
+    GnssSpecific = 0,
+    /// Use the main talker ID
+    Main = 1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes = CfgNmeaBuilder {
+            nmea_version: NmeaVersion::V411,
+            num_sv: 12,
+            flags: CfgNmeaFlags::HIGH_PREC,
+            gnss_to_filter: CfgNmeaGnssFilter::SBAS | CfgNmeaGnssFilter::QZSS,
+            main_talker_id: NmeaMainTalkerId::Gn,
+            ..Default::default()
+        }
+        .into_packet_bytes();
+        let payload = &bytes[6..26];
+        assert_eq!(
+            &payload[..12],
+            &[0x00, 0x4b, 0x0c, 0x08, 0x12, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01]
+        );
+
+        let packet = CfgNmeaRef(payload);
+        assert_eq!(packet.nmea_version(), NmeaVersion::V411);
+        assert_eq!(packet.num_sv(), 12);
+        assert_eq!(packet.flags(), CfgNmeaFlags::HIGH_PREC);
+        assert_eq!(
+            packet.gnss_to_filter(),
+            CfgNmeaGnssFilter::SBAS | CfgNmeaGnssFilter::QZSS
+        );
+        assert_eq!(packet.main_talker_id(), NmeaMainTalkerId::Gn);
+        assert_eq!(packet.gsv_talker_id(), NmeaGsvTalkerId::GnssSpecific);
+        assert_eq!(packet.version(), 1);
+    }
+}
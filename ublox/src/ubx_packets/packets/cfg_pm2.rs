@@ -0,0 +1,232 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::{ParserError, PowerManagementError},
+    ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::ubx_packet_recv_send;
+
+/// Extended power management configuration
+///
+/// Parameters of the cyclic tracking and ON/OFF power save modes, which are
+/// entered with `CfgRxm`. Message version 0x02 (protocol 18 and later).
+#[ubx_packet_recv_send]
+#[ubx(class = 0x06, id = 0x3b, fixed_payload_len = 48)]
+struct CfgPm2 {
+    /// Message version (0x02 for this version)
+    version: u8,
+    reserved1: u8,
+
+    /// Maximum time to spend in acquisition state (s), 0 means the receiver decides
+    max_startup_state_dur: u8,
+    reserved2: u8,
+
+    /// PSM configuration flags, see [CfgPm2Flags]
+    #[ubx(map_type = CfgPm2Flags)]
+    flags: u32,
+
+    /// Position update period (ms), 0 means the receiver never retries a fix
+    /// and waits for external events
+    update_period: u32,
+
+    /// Acquisition retry period if previously failed (ms), 0 means the receiver
+    /// never retries a startup
+    search_period: u32,
+
+    /// Grid offset relative to GPS start of week (ms)
+    grid_offset: u32,
+
+    /// Time to stay in tracking state (s)
+    on_time: u16,
+
+    /// Minimal search time (s)
+    min_acq_time: u16,
+
+    reserved3: [u8; 20],
+
+    /// Inactivity time out on EXTINT pin if enabled (ms)
+    extint_inactivity_ms: u32,
+}
+
+impl Default for CfgPm2Builder {
+    fn default() -> Self {
+        Self {
+            version: 2,
+            reserved1: 0,
+            max_startup_state_dur: 0,
+            reserved2: 0,
+            flags: CfgPm2Flags::default(),
+            update_period: 1000,
+            search_period: 10000,
+            grid_offset: 0,
+            on_time: 0,
+            min_acq_time: 0,
+            reserved3: [0; 20],
+            extint_inactivity_ms: 0,
+        }
+    }
+}
+
+impl CfgPm2Builder {
+    /// Checks the settings against the combinations accepted by the receiver
+    pub fn validate(&self) -> Result<(), PowerManagementError> {
+        if self.flags.mode == CfgPm2Mode::OnOff
+            && self.update_period != 0
+            && u32::from(self.on_time) * 1000 >= self.update_period
+        {
+            return Err(PowerManagementError::OnTimeNotShorterThanPeriod);
+        }
+        Ok(())
+    }
+
+    /// Same as `into_packet_bytes`, but rejects settings the receiver would NAK
+    pub fn try_into_packet_bytes(self) -> Result<[u8; Self::PACKET_LEN], PowerManagementError> {
+        self.validate()?;
+        Ok(self.into_packet_bytes())
+    }
+}
+
+/// Power save mode flags
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CfgPm2Flags {
+    /// EXTINT pin used by the other EXTINT flags
+    pub extint_sel: CfgPm2ExtintSel,
+    /// Keep the receiver awake as long as the selected EXTINT pin is high
+    pub extint_wake: bool,
+    /// Force the receiver into backup mode when the selected EXTINT pin is low
+    pub extint_backup: bool,
+    /// Force backup in case the EXTINT pin is inactive for longer than `extint_inactivity_ms`
+    pub extint_inactive: bool,
+    /// Limit peak current
+    pub limit_peak_current: bool,
+    /// Wait for timing fix before entering power save
+    pub wait_time_fix: bool,
+    /// Update real time clock
+    pub update_rtc: bool,
+    /// Update ephemeris
+    pub update_eph: bool,
+    /// Do not enter the inactive state when no fix could be obtained
+    pub do_not_enter_off: bool,
+    /// Power save mode
+    pub mode: CfgPm2Mode,
+}
+
+impl CfgPm2Flags {
+    const fn into_raw(self) -> u32 {
+        ((self.extint_sel as u32) << 4)
+            | ((self.extint_wake as u32) << 5)
+            | ((self.extint_backup as u32) << 6)
+            | ((self.extint_inactive as u32) << 7)
+            | ((self.limit_peak_current as u32) << 8)
+            | ((self.wait_time_fix as u32) << 10)
+            | ((self.update_rtc as u32) << 11)
+            | ((self.update_eph as u32) << 12)
+            | ((self.do_not_enter_off as u32) << 16)
+            | ((self.mode as u32) << 17)
+    }
+}
+
+impl From<u32> for CfgPm2Flags {
+    fn from(flags: u32) -> Self {
+        let bit = |n: u32| (flags >> n) & 0x01 == 1;
+        Self {
+            extint_sel: if bit(4) {
+                CfgPm2ExtintSel::ExtInt1
+            } else {
+                CfgPm2ExtintSel::ExtInt0
+            },
+            extint_wake: bit(5),
+            extint_backup: bit(6),
+            extint_inactive: bit(7),
+            limit_peak_current: (flags >> 8) & 0x03 == 1,
+            wait_time_fix: bit(10),
+            update_rtc: bit(11),
+            update_eph: bit(12),
+            do_not_enter_off: bit(16),
+            mode: if (flags >> 17) & 0x03 == 1 {
+                CfgPm2Mode::CyclicTracking
+            } else {
+                CfgPm2Mode::OnOff
+            },
+        }
+    }
+}
+
+/// EXTINT pin selection
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CfgPm2ExtintSel {
+    #[default]
+    ExtInt0 = 0,
+    ExtInt1 = 1,
+}
+
+/// Power save mode
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CfgPm2Mode {
+    /// ON/OFF operation, for long update periods
+    #[default]
+    OnOff = 0,
+    /// Cyclic tracking operation, for short update periods
+    CyclicTracking = 1,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let flags = CfgPm2Flags {
+            extint_sel: CfgPm2ExtintSel::ExtInt1,
+            extint_wake: true,
+            update_eph: true,
+            mode: CfgPm2Mode::CyclicTracking,
+            ..Default::default()
+        };
+        let bytes = CfgPm2Builder {
+            flags,
+            update_period: 5000,
+            extint_inactivity_ms: 2000,
+            ..Default::default()
+        }
+        .try_into_packet_bytes()
+        .unwrap();
+        let payload = &bytes[6..bytes.len() - 2];
+        assert_eq!(&payload[4..8], &[0x30, 0x10, 0x02, 0x00]);
+        assert!(CfgPm2Ref::validate(payload).is_ok());
+
+        let packet = CfgPm2Ref(payload);
+        assert_eq!(packet.version(), 2);
+        assert_eq!(packet.update_period(), 5000);
+        assert_eq!(packet.search_period(), 10000);
+        assert_eq!(packet.extint_inactivity_ms(), 2000);
+        assert_eq!(packet.flags(), flags);
+    }
+
+    #[test]
+    fn on_time_must_fit_in_period() {
+        let builder = CfgPm2Builder {
+            update_period: 10_000,
+            on_time: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            builder.validate(),
+            Err(PowerManagementError::OnTimeNotShorterThanPeriod)
+        );
+
+        let builder = CfgPm2Builder {
+            on_time: 9,
+            ..builder
+        };
+        assert!(builder.validate().is_ok());
+    }
+}
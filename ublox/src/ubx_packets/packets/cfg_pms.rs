@@ -0,0 +1,151 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::{ParserError, PowerManagementError},
+    ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend, ubx_packet_recv_send};
+
+/// Power mode setup
+///
+/// Selects one of the predefined power setup values. `period` and `on_time`
+/// are only used with [CfgPmsPowerSetup::Interval].
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x86,
+    fixed_payload_len = 8,
+    flags = "default_for_builder"
+)]
+struct CfgPms {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Power setup value, see [CfgPmsPowerSetup]
+    #[ubx(map_type = CfgPmsPowerSetup)]
+    power_setup_value: u8,
+
+    /// Position update period and search period (s)
+    period: u16,
+
+    /// Duration of the ON phase (s)
+    on_time: u16,
+
+    reserved1: [u8; 2],
+}
+
+impl CfgPmsBuilder {
+    /// Interval mode with the given update period and ON phase duration (s)
+    pub fn interval(period: u16, on_time: u16) -> Self {
+        Self {
+            power_setup_value: CfgPmsPowerSetup::Interval,
+            period,
+            on_time,
+            ..Default::default()
+        }
+    }
+
+    /// Checks the settings against the combinations accepted by the receiver
+    pub fn validate(&self) -> Result<(), PowerManagementError> {
+        match self.power_setup_value {
+            CfgPmsPowerSetup::Interval => {
+                if self.period <= 5 {
+                    return Err(PowerManagementError::PeriodTooShort);
+                }
+                if self.on_time >= self.period {
+                    return Err(PowerManagementError::OnTimeNotShorterThanPeriod);
+                }
+            },
+            CfgPmsPowerSetup::Invalid => {
+                return Err(PowerManagementError::InvalidPowerSetup);
+            },
+            _ => {
+                if self.period != 0 || self.on_time != 0 {
+                    return Err(PowerManagementError::UnexpectedInterval);
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Same as `into_packet_bytes`, but rejects settings the receiver would NAK
+    pub fn try_into_packet_bytes(self) -> Result<[u8; Self::PACKET_LEN], PowerManagementError> {
+        self.validate()?;
+        Ok(self.into_packet_bytes())
+    }
+}
+
+/// Predefined power setup
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from, into_raw, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgPmsPowerSetup {
+    #[default]
+    FullPower = 0x00,
+    Balanced = 0x01,
+    /// Periodic operation, see `period` and `on_time`
+    Interval = 0x02,
+    Aggressive1Hz = 0x03,
+    Aggressive2Hz = 0x04,
+    Aggressive4Hz = 0x05,
+    /// Only reported when polling, cannot be set
+    Invalid = 0xff,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interval_round_trip() {
+        let bytes = CfgPmsBuilder::interval(10, 2)
+            .try_into_packet_bytes()
+            .unwrap();
+        assert_eq!(
+            bytes,
+            [
+                0xb5, 0x62, 0x06, 0x86, 0x08, 0x00, 0x00, 0x02, 0x0a, 0x00, 0x02, 0x00, 0x00, 0x00,
+                0xa2, 0xac
+            ]
+        );
+        let packet = CfgPmsRef(&bytes[6..14]);
+        assert_eq!(packet.power_setup_value(), CfgPmsPowerSetup::Interval);
+        assert_eq!(packet.period(), 10);
+        assert_eq!(packet.on_time(), 2);
+    }
+
+    #[test]
+    fn rejected_combinations() {
+        assert_eq!(
+            CfgPmsBuilder::interval(5, 2).validate(),
+            Err(PowerManagementError::PeriodTooShort)
+        );
+        assert_eq!(
+            CfgPmsBuilder::interval(10, 10).validate(),
+            Err(PowerManagementError::OnTimeNotShorterThanPeriod)
+        );
+        let balanced = CfgPmsBuilder {
+            power_setup_value: CfgPmsPowerSetup::Balanced,
+            period: 10,
+            ..Default::default()
+        };
+        assert_eq!(
+            balanced.validate(),
+            Err(PowerManagementError::UnexpectedInterval)
+        );
+        let invalid = CfgPmsBuilder {
+            power_setup_value: CfgPmsPowerSetup::Invalid,
+            ..Default::default()
+        };
+        assert_eq!(
+            invalid.validate(),
+            Err(PowerManagementError::InvalidPowerSetup)
+        );
+        assert!(CfgPmsBuilder::default().validate().is_ok());
+    }
+}
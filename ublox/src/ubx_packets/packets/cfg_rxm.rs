@@ -0,0 +1,54 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend, ubx_packet_recv_send};
+
+/// RXM configuration
+///
+/// Selects the low power mode, whose parameters are set with `CfgPm2`.
+#[ubx_packet_recv_send]
+#[ubx(class = 0x06, id = 0x11, fixed_payload_len = 2)]
+struct CfgRxm {
+    /// Always set to 8
+    reserved1: u8,
+
+    /// Low power mode, see [CfgRxmLowPowerMode]
+    #[ubx(map_type = CfgRxmLowPowerMode)]
+    lp_mode: u8,
+}
+
+impl Default for CfgRxmBuilder {
+    fn default() -> Self {
+        Self {
+            reserved1: 8,
+            lp_mode: CfgRxmLowPowerMode::default(),
+        }
+    }
+}
+
+impl CfgRxmBuilder {
+    pub fn with_lp_mode(mut self, lp_mode: CfgRxmLowPowerMode) -> Self {
+        self.lp_mode = lp_mode;
+        self
+    }
+}
+
+/// Low power mode
+#[derive(Default)]
+#[ubx_extend]
+#[ubx(from, into_raw, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgRxmLowPowerMode {
+    #[default]
+    Continuous = 0,
+    /// Power save mode, configured with `CfgPm2`
+    PowerSave = 1,
+    /// Eco mode on u-blox 6, treated as continuous mode by later receivers
+    Eco = 4,
+}
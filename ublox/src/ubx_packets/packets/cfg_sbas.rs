@@ -0,0 +1,195 @@
+use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv_send};
+
+/// SBAS configuration
+/// Deprecated in protocol versions above 23, use CfgValSet and CfgValGet for newer protocol version
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x16,
+    fixed_payload_len = 8,
+    flags = "default_for_builder"
+)]
+struct CfgSbas {
+    /// SBAS mode, see [CfgSbasMode]
+    #[ubx(map_type = CfgSbasMode)]
+    mode: u8,
+
+    /// SBAS usage, see [CfgSbasUsage]
+    #[ubx(map_type = CfgSbasUsage)]
+    usage: u8,
+
+    /// Maximum number of SBAS prioritized tracking channels (valid range: 0 - 3)
+    /// to use (obsolete and superseded by UBX-CFG-GNSS in protocol versions 14+)
+    max_sbas: u8,
+
+    /// Continuation of scanmode bitmask below, PRN 152..158 in bits 0..6
+    scanmode2: u8,
+
+    /// Which SBAS PRN numbers to search for (bitmask), PRN 120..151 in bits 0..31.
+    /// If all bits of both scanmode fields are set to zero, auto-scan (i.e. all valid PRNs)
+    /// are searched. Every bit corresponds to a PRN number.
+    scanmode1: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// SBAS mode for `CfgSbas`
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgSbasMode: u8 {
+        /// SBAS enabled
+        const ENABLED = 0x01;
+        /// SBAS testbed: use data anyhow
+        const TEST = 0x02;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// SBAS usage for `CfgSbas`
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgSbasUsage: u8 {
+        /// Use SBAS GEOs as a ranging source (for navigation)
+        const RANGE = 0x01;
+        /// Use SBAS differential corrections
+        const DIFF_CORR = 0x02;
+        /// Use SBAS integrity information
+        const INTEGRITY = 0x04;
+    }
+}
+
+bitflags! {
+    /// Mask of the SBAS PRNs to search for, PRN 120 in bit 0 up to PRN 158 in bit 38.
+    /// An empty mask means auto-scan (all valid PRNs are searched).
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct SbasPrnMask: u64 {
+        const PRN120 = 0x1;
+        const PRN121 = 0x2;
+        const PRN122 = 0x4;
+        const PRN123 = 0x8;
+        const PRN124 = 0x10;
+        const PRN125 = 0x20;
+        const PRN126 = 0x40;
+        const PRN127 = 0x80;
+        const PRN128 = 0x100;
+        const PRN129 = 0x200;
+        const PRN130 = 0x400;
+        const PRN131 = 0x800;
+        const PRN132 = 0x1000;
+        const PRN133 = 0x2000;
+        const PRN134 = 0x4000;
+        const PRN135 = 0x8000;
+        const PRN136 = 0x10000;
+        const PRN137 = 0x20000;
+        const PRN138 = 0x40000;
+        const PRN139 = 0x80000;
+        const PRN140 = 0x100000;
+        const PRN141 = 0x200000;
+        const PRN142 = 0x400000;
+        const PRN143 = 0x800000;
+        const PRN144 = 0x1000000;
+        const PRN145 = 0x2000000;
+        const PRN146 = 0x4000000;
+        const PRN147 = 0x8000000;
+        const PRN148 = 0x10000000;
+        const PRN149 = 0x20000000;
+        const PRN150 = 0x40000000;
+        const PRN151 = 0x80000000;
+        const PRN152 = 0x100000000;
+        const PRN153 = 0x200000000;
+        const PRN154 = 0x400000000;
+        const PRN155 = 0x800000000;
+        const PRN156 = 0x1000000000;
+        const PRN157 = 0x2000000000;
+        const PRN158 = 0x4000000000;
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SbasPrnMask {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u64(self.bits())
+    }
+}
+
+impl SbasPrnMask {
+    /// Returns the mask bit for an SBAS `prn` (120..=158)
+    pub fn from_prn(prn: u8) -> Option<Self> {
+        match prn {
+            120..=158 => Some(Self::from_bits_retain(1 << (prn - 120))),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_prn_mask {
+    ($type:ty) => {
+        impl $type {
+            /// PRNs searched for, combining `scanmode1` and `scanmode2`
+            pub fn prn_mask(&self) -> SbasPrnMask {
+                SbasPrnMask::from_bits_retain(
+                    u64::from(self.scanmode1()) | (u64::from(self.scanmode2()) << 32),
+                )
+            }
+        }
+    };
+}
+
+impl_prn_mask!(CfgSbasRef<'_>);
+impl_prn_mask!(CfgSbasOwned);
+
+impl CfgSbasBuilder {
+    /// Sets both `scanmode1` and `scanmode2` from a PRN mask
+    pub fn with_prn_mask(mut self, mask: SbasPrnMask) -> Self {
+        let bits = mask.bits();
+        self.scanmode1 = bits as u32;
+        self.scanmode2 = (bits >> 32) as u8;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prn_mask_round_trip() {
+        let mask = SbasPrnMask::PRN120 | SbasPrnMask::PRN136 | SbasPrnMask::PRN158;
+        let bytes = CfgSbasBuilder {
+            mode: CfgSbasMode::ENABLED,
+            usage: CfgSbasUsage::RANGE | CfgSbasUsage::DIFF_CORR | CfgSbasUsage::INTEGRITY,
+            max_sbas: 3,
+            ..Default::default()
+        }
+        .with_prn_mask(mask)
+        .into_packet_bytes();
+
+        assert_eq!(
+            bytes,
+            [
+                0xb5, 0x62, 0x06, 0x16, 0x08, 0x00, 0x01, 0x07, 0x03, 0x40, 0x01, 0x00, 0x01, 0x00,
+                0x71, 0x1b
+            ]
+        );
+
+        let packet = CfgSbasRef(&bytes[6..14]);
+        assert_eq!(packet.mode(), CfgSbasMode::ENABLED);
+        assert_eq!(packet.prn_mask(), mask);
+        assert_eq!(SbasPrnMask::from_prn(136), Some(SbasPrnMask::PRN136));
+        assert_eq!(SbasPrnMask::from_prn(159), None);
+    }
+}
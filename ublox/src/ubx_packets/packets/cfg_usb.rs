@@ -0,0 +1,153 @@
+use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use super::mon_ver::fixed_size_str;
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv_send};
+
+/// USB configuration
+///
+/// Descriptor strings are NUL padded ASCII, see `vendor_str()`,
+/// `product_str()` and `serial_number_str()`.
+#[ubx_packet_recv_send]
+#[ubx(class = 0x06, id = 0x1b, fixed_payload_len = 108)]
+struct CfgUsb {
+    /// Vendor ID, should only be changed together with the product ID
+    vendor_id: u16,
+
+    /// Product ID
+    product_id: u16,
+
+    reserved1: [u8; 2],
+    reserved2: [u8; 2],
+
+    /// Power consumption during active operation (mA)
+    power_consumption: u16,
+
+    /// USB flags, see [CfgUsbFlags]
+    #[ubx(map_type = CfgUsbFlags)]
+    flags: u16,
+
+    /// Vendor string
+    vendor_string: [u8; 32],
+
+    /// Product string
+    product_string: [u8; 32],
+
+    /// Serial number string
+    serial_number: [u8; 32],
+}
+
+macro_rules! impl_usb_strings {
+    ($type:ty) => {
+        impl $type {
+            /// Vendor string, `None` if it is not valid ASCII
+            pub fn vendor_str(&self) -> Option<&str> {
+                fixed_size_str(&self.0[12..44])
+            }
+
+            /// Product string, `None` if it is not valid ASCII
+            pub fn product_str(&self) -> Option<&str> {
+                fixed_size_str(&self.0[44..76])
+            }
+
+            /// Serial number string, `None` if it is not valid ASCII
+            pub fn serial_number_str(&self) -> Option<&str> {
+                fixed_size_str(&self.0[76..108])
+            }
+        }
+    };
+}
+
+impl_usb_strings!(CfgUsbRef<'_>);
+impl_usb_strings!(CfgUsbOwned);
+
+impl Default for CfgUsbBuilder {
+    fn default() -> Self {
+        Self {
+            vendor_id: 0x1546,
+            product_id: 0x01a8,
+            reserved1: [0; 2],
+            reserved2: [0; 2],
+            power_consumption: 0,
+            flags: CfgUsbFlags::default(),
+            vendor_string: [0; 32],
+            product_string: [0; 32],
+            serial_number: [0; 32],
+        }
+    }
+}
+
+impl CfgUsbBuilder {
+    /// Sets the vendor string, truncated to 32 bytes
+    pub fn with_vendor_string(mut self, s: &str) -> Self {
+        self.vendor_string = usb_string(s);
+        self
+    }
+
+    /// Sets the product string, truncated to 32 bytes
+    pub fn with_product_string(mut self, s: &str) -> Self {
+        self.product_string = usb_string(s);
+        self
+    }
+
+    /// Sets the serial number string, truncated to 32 bytes
+    pub fn with_serial_number(mut self, s: &str) -> Self {
+        self.serial_number = usb_string(s);
+        self
+    }
+}
+
+fn usb_string(s: &str) -> [u8; 32] {
+    let mut ret = [0; 32];
+    let len = s.len().min(ret.len());
+    ret[..len].copy_from_slice(&s.as_bytes()[..len]);
+    ret
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// USB flags
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgUsbFlags: u16 {
+        /// Force re-enumeration
+        const RE_ENUM = 0x01;
+        /// Self-powered device, bus-powered otherwise
+        const SELF_POWERED = 0x02;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes = CfgUsbBuilder {
+            power_consumption: 100,
+            flags: CfgUsbFlags::SELF_POWERED,
+            ..Default::default()
+        }
+        .with_vendor_string("u-blox AG - www.u-blox.com")
+        .with_product_string("u-blox GNSS receiver")
+        .into_packet_bytes();
+        let payload = &bytes[6..114];
+        assert!(CfgUsbRef::validate(payload).is_ok());
+
+        let packet = CfgUsbRef(payload);
+        assert_eq!(packet.vendor_id(), 0x1546);
+        assert_eq!(packet.product_id(), 0x01a8);
+        assert_eq!(packet.power_consumption(), 100);
+        assert_eq!(packet.flags(), CfgUsbFlags::SELF_POWERED);
+        assert_eq!(packet.vendor_str(), Some("u-blox AG - www.u-blox.com"));
+        assert_eq!(packet.product_str(), Some("u-blox GNSS receiver"));
+        assert_eq!(packet.serial_number_str(), Some(""));
+    }
+}
@@ -3,13 +3,13 @@ use bitflags::bitflags;
 #[allow(unused_imports, reason = "It is only unused in some feature sets")]
 use crate::FieldIter;
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 #[cfg(feature = "serde")]
 use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
 
 use crate::{
     cfg_val::{CfgKey, CfgVal},
-    error::ParserError,
+    error::{CfgValError, ParserError},
     ubx_checksum, UbxPacketMeta,
 };
 use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv, ubx_packet_send};
@@ -55,6 +55,24 @@ struct CfgValGetRequest<'a> {
     cfg_keys: &'a [CfgKey],
 }
 
+#[ubx_packet_send]
+#[ubx(
+  class = 0x06,
+  id = 0x8c,
+  max_payload_len = 260, // 4 + sizeof(u32) * MAX_CFG_KEYS
+)]
+/// Deletes configuration values from the BBR and Flash layers, so that the
+/// values of the lower layers apply. The RAM layer cannot be deleted from.
+struct CfgValDel<'a> {
+    /// Message version
+    version: u8,
+    /// The layers from which the configuration items should be deleted
+    #[ubx(map_type = CfgLayerSet)]
+    layers: u8,
+    reserved1: u16,
+    cfg_keys: &'a [CfgKey],
+}
+
 #[ubx_packet_recv]
 #[ubx(
   class = 0x06,
@@ -104,25 +122,27 @@ impl<'a> CfgValIter<'a> {
         Self { data }
     }
 
+    #[allow(dead_code, reason = "Used by ubx_packet_recv macro for validation")]
     fn is_valid(bytes: &[u8]) -> bool {
         // we need at least 5 bytes for a key id (4) + val (1)
         bytes.len() >= 5
     }
 }
 
+/// Keys unknown to this crate, and values it cannot represent, are returned
+/// as [CfgVal::Unknown]. Iteration stops at a truncated value or at a key ID
+/// with reserved size bits.
 impl core::iter::Iterator for CfgValIter<'_> {
     type Item = CfgVal;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if Self::is_valid(self.data) {
-            if let Some(cfg_val) = CfgVal::parse(self.data) {
-                self.data = &self.data[cfg_val.len()..];
-                return Some(cfg_val);
-            }
-            // TODO: Is there some logging mechanism?
-            // eprintln!("Failure parsing key in (key,value) list, {:?}", self.data);
+        let cfg_val = match CfgVal::parse(self.data) {
+            Err(CfgValError::InvalidValue(_)) => CfgVal::parse_unknown(self.data),
+            result => result,
         }
-        None
+        .ok()?;
+        self.data = &self.data[cfg_val.len()..];
+        Some(cfg_val)
     }
 }
 
@@ -133,6 +153,7 @@ impl core::iter::Iterator for CfgValIter<'_> {
 #[ubx(from, into_raw, rest_reserved)]
 bitflags! {
     /// A mask describing where configuration is applied.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct CfgLayerSet: u8 {
         const RAM = 0b001;
         const BBR = 0b010;
@@ -145,3 +166,238 @@ impl Default for CfgLayerSet {
         Self::RAM | Self::BBR | Self::FLASH
     }
 }
+
+/// Configuration read with paginated CFG-VALGET requests
+///
+/// The receiver answers a request for a wildcard key, such as
+/// [CfgKey::WildcardAll] or a group wildcard like [CfgKey::WildcardRate], with
+/// at most [MAX_CFG_KEYS] values. The request is repeated with `position`
+/// advanced past the values received, until a response holds fewer values or
+/// the receiver answers with UBX-ACK-NAK.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use ublox::{cfg_val::CfgKey, packets::cfg_val::{CfgLayerGet, CfgValDump}};
+/// let values = CfgValDump::read(
+///     CfgKey::WildcardAll,
+///     CfgLayerGet::Ram,
+///     |request| port.write_all(request),
+///     // Next CfgValGetResponse from the parser, `None` on UBX-ACK-NAK
+///     || next_response(&mut port),
+/// )?;
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct CfgValDump {
+    key: CfgKey,
+    layer: CfgLayerGet,
+    position: u16,
+    complete: bool,
+    values: BTreeMap<u32, CfgVal>,
+}
+
+#[cfg(feature = "alloc")]
+impl CfgValDump {
+    /// Dump of the values of `key` in `layer`
+    pub fn new(key: CfgKey, layer: CfgLayerGet) -> Self {
+        Self {
+            key,
+            layer,
+            position: 0,
+            complete: false,
+            values: BTreeMap::new(),
+        }
+    }
+
+    /// Reads all the values of `key` in `layer`, sending the requests with
+    /// `send` and receiving the responses with `receive`, which returns `None`
+    /// when the receiver answers with UBX-ACK-NAK
+    pub fn read<E>(
+        key: CfgKey,
+        layer: CfgLayerGet,
+        mut send: impl FnMut(&[u8]) -> Result<(), E>,
+        mut receive: impl FnMut() -> Result<Option<CfgValGetResponseOwned>, E>,
+    ) -> Result<BTreeMap<u32, CfgVal>, E> {
+        let mut dump = Self::new(key, layer);
+        while let Some(request) = dump.next_request() {
+            send(&request.into_packet_vec())?;
+            match receive()? {
+                Some(response) => dump.push(&CfgValGetResponseRef(response.as_bytes())),
+                None => dump.push_nak(),
+            }
+        }
+        Ok(dump.into_values())
+    }
+
+    /// Request for the next page of values, `None` once the dump is complete
+    pub fn next_request(&self) -> Option<CfgValGetRequestBuilder<'_>> {
+        if self.complete {
+            return None;
+        }
+        Some(CfgValGetRequestBuilder {
+            version: 0,
+            layers: self.layer,
+            position: self.position,
+            cfg_keys: core::slice::from_ref(&self.key),
+        })
+    }
+
+    /// Adds the values of the response to the last request
+    pub fn push(&mut self, response: &CfgValGetResponseRef<'_>) {
+        let mut count = 0;
+        for value in response.cfg_data() {
+            self.values.insert(value.key_id(), value);
+            count += 1;
+        }
+        self.position = self.position.saturating_add(count);
+        self.complete = count < MAX_CFG_KEYS;
+    }
+
+    /// Ends the dump after the receiver rejected the last request, which it
+    /// does when no value is left to read
+    pub fn push_nak(&mut self) {
+        self.complete = true;
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Values read so far, by key ID
+    pub fn values(&self) -> &BTreeMap<u32, CfgVal> {
+        &self.values
+    }
+
+    pub fn into_values(self) -> BTreeMap<u32, CfgVal> {
+        self.values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cfg_val::{CfgKey, UnknownCfgVal};
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn iter_passes_through_unknown_keys() {
+        #[rustfmt::skip]
+        let payload = [
+            0x01, 0x00, 0x00, 0x00, // version, layers, position
+            0x01, 0x00, 0x21, 0x30, 0xe8, 0x03, // CFG-RATE-MEAS = 1000
+            0x01, 0x00, 0xfe, 0x40, 0x01, 0x02, 0x03, 0x04, // unknown U4 key
+            0x21, 0x00, 0x11, 0x20, 0xff, // CFG-NAVSPG-DYNMODEL, invalid value
+            0x04, 0x00, 0x31, 0x10, 0x01, // CFG-SIGNAL-GPS_L5_ENA = true
+        ];
+        assert!(CfgValGetResponseRef::validate(&payload).is_ok());
+
+        let vals: Vec<CfgVal> = CfgValGetResponseRef(&payload).cfg_data().collect();
+        assert_eq!(
+            vals,
+            [
+                CfgVal::RateMeas(1000),
+                CfgVal::Unknown(UnknownCfgVal::new(0x40fe0001, &[0x01, 0x02, 0x03, 0x04]).unwrap()),
+                CfgVal::Unknown(
+                    UnknownCfgVal::new(CfgKey::NavSpgDynModel as u32, &[0xff]).unwrap()
+                ),
+                CfgVal::SignalGpsL5Ena(true),
+            ]
+        );
+    }
+
+    #[test]
+    fn iter_stops_at_truncated_value() {
+        let payload = [
+            0x01, 0x00, 0x00, 0x00, // version, layers, position
+            0x04, 0x00, 0x31, 0x10, 0x01, // CFG-SIGNAL-GPS_L5_ENA = true
+            0x01, 0x00, 0x21, 0x30, 0xe8, // truncated CFG-RATE-MEAS
+        ];
+        let packet = CfgValGetResponseRef(&payload);
+        let mut iter = packet.cfg_data();
+        assert_eq!(iter.next(), Some(CfgVal::SignalGpsL5Ena(true)));
+        assert_eq!(iter.next(), None);
+    }
+
+    /// Receiver holding `count` one-byte values, answering the `request` frame
+    /// with a NAK when no value is left
+    #[cfg(feature = "alloc")]
+    fn respond(request: &[u8], count: u16) -> Option<CfgValGetResponseOwned> {
+        let position = u16::from_le_bytes([request[8], request[9]]);
+        if position >= count {
+            return None;
+        }
+        let mut payload = alloc::vec![0x01, request[7], request[8], request[9]];
+        for id in position..count.min(position + MAX_CFG_KEYS) {
+            payload.extend((0x20fe_0000 | u32::from(id)).to_le_bytes());
+            payload.push(id as u8);
+        }
+        assert!(CfgValGetResponseRef::validate(&payload).is_ok());
+        Some(CfgValGetResponseRef(&payload).to_owned())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn read_dump(count: u16) -> (BTreeMap<u32, CfgVal>, Vec<u16>) {
+        let mut positions = Vec::new();
+        let last_request = core::cell::RefCell::new(Vec::new());
+        let values = CfgValDump::read::<()>(
+            CfgKey::WildcardAll,
+            CfgLayerGet::Flash,
+            |request| {
+                assert_eq!(request[2..4], [0x06, 0x8b]);
+                assert_eq!(request[7], CfgLayerGet::Flash as u8);
+                assert_eq!(request[10..14], 0x7fff_ffffu32.to_le_bytes());
+                positions.push(u16::from_le_bytes([request[8], request[9]]));
+                *last_request.borrow_mut() = request.to_vec();
+                Ok(())
+            },
+            || Ok(respond(&last_request.borrow(), count)),
+        )
+        .unwrap();
+        (values, positions)
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn dump_pages() {
+        let (values, positions) = read_dump(150);
+        assert_eq!(positions, [0, 64, 128]);
+        assert_eq!(values.len(), 150);
+        assert_eq!(
+            values[&0x20fe_0095],
+            CfgVal::Unknown(UnknownCfgVal::new(0x20fe_0095, &[0x95]).unwrap())
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn dump_ends_with_nak() {
+        let (values, positions) = read_dump(128);
+        assert_eq!(positions, [0, 64, 128]);
+        assert_eq!(values.len(), 128);
+
+        let (values, positions) = read_dump(0);
+        assert_eq!(positions, [0]);
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn group_wildcard_request() {
+        let mut dump = CfgValDump::new(CfgKey::WildcardRate, CfgLayerGet::Ram);
+        let request = dump.next_request().unwrap().into_packet_vec();
+        assert_eq!(request[10..14], [0xff, 0xff, 0x21, 0x7f]);
+
+        let payload = [
+            0x01, 0x00, 0x00, 0x00, // version, layers, position
+            0x01, 0x00, 0x21, 0x30, 0xe8, 0x03, // CFG-RATE-MEAS = 1000
+        ];
+        dump.push(&CfgValGetResponseRef(&payload));
+        assert!(dump.is_complete());
+        assert!(dump.next_request().is_none());
+        assert_eq!(
+            dump.values().get(&(CfgKey::RateMeas as u32)),
+            Some(&CfgVal::RateMeas(1000))
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use chrono::NaiveDate;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::ubx_packet_recv_send;
+
+/// AssistNow Offline data for a single satellite and day
+///
+/// AssistNow Offline files are a sequence of these messages, which can be sent to
+/// the receiver as they are, or stored in its flash with `MgaFlashData`.
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x20, fixed_payload_len = 76)]
+struct MgaAno {
+    /// Message type (0x00 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Satellite identifier
+    sv_id: u8,
+
+    /// GNSS identifier
+    gnss_id: u8,
+
+    /// Years since the year 2000
+    year: u8,
+
+    /// Month (1..12)
+    month: u8,
+
+    /// Day (1..31)
+    day: u8,
+
+    reserved1: u8,
+
+    /// Assistance data
+    data: [u8; 64],
+
+    reserved2: [u8; 4],
+}
+
+macro_rules! impl_date {
+    ($type:ty) => {
+        impl $type {
+            /// Day the data is valid for, `None` if the date is invalid
+            pub fn date(&self) -> Option<NaiveDate> {
+                NaiveDate::from_ymd_opt(
+                    2000 + i32::from(self.year()),
+                    self.month().into(),
+                    self.day().into(),
+                )
+            }
+        }
+    };
+}
+
+impl_date!(MgaAnoRef<'_>);
+impl_date!(MgaAnoOwned);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut data = [0u8; 64];
+        data[0] = 0xaa;
+        data[63] = 0x55;
+        let bytes = MgaAnoBuilder {
+            msg_type: 0,
+            version: 0,
+            sv_id: 7,
+            gnss_id: 0,
+            year: 24,
+            month: 2,
+            day: 29,
+            reserved1: 0,
+            data,
+            reserved2: [0; 4],
+        }
+        .into_packet_bytes();
+        let payload = &bytes[6..82];
+        assert!(MgaAnoRef::validate(payload).is_ok());
+
+        let packet = MgaAnoRef(payload);
+        assert_eq!(packet.sv_id(), 7);
+        assert_eq!(packet.date(), NaiveDate::from_ymd_opt(2024, 2, 29));
+        assert_eq!(packet.data(), data);
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, ubx_packets::packets::ScaleBack, MemWriter, MemWriterError,
+    UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-BDS ALM frame, almanac of a single BeiDou satellite
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x03, fixed_payload_len = 40)]
+struct MgaBdsAlm {
+    /// Message type: 0x02 for this type
+    msg_type: u8,
+
+    /// Message version: 0x00 for this version
+    version: u8,
+
+    /// BeiDou satellite identifier
+    sv_id: u8,
+
+    /// Reserved
+    reserved1: u8,
+
+    /// Almanac week number
+    wna: u8,
+
+    /// Almanac reference time (in seconds)
+    #[ubx(map_type = f64, scale = 4096.0)]
+    toa_s: u8,
+
+    /// Almanac correction of orbit reference inclination at reference time (in semi-circles)
+    #[ubx(map_type = f64, scale = 1.9073486328125e-06)]
+    delta_i_semicircles: i16,
+
+    /// Almanac square root of semi-major axis (in square root of meters)
+    #[ubx(map_type = f64, scale = 0.00048828125)]
+    sqrt_a: u32,
+
+    /// Almanac eccentricity
+    #[ubx(map_type = f64, scale = 4.76837158203125e-07)]
+    e: u32,
+
+    /// Almanac argument of perigee (in semi-circles)
+    #[ubx(map_type = f64, scale = 1.1920928955078125e-07)]
+    omega_semicircles: i32,
+
+    /// Almanac mean anomaly at reference time (in semi-circles)
+    #[ubx(map_type = f64, scale = 1.1920928955078125e-07)]
+    m0_semicircles: i32,
+
+    /// Almanac longitude of ascending node of orbit plane at computed according to
+    /// reference time (in semi-circles)
+    #[ubx(map_type = f64, scale = 1.1920928955078125e-07)]
+    omega0_semicircles: i32,
+
+    /// Almanac rate of right ascension (in semi-circles per second)
+    #[ubx(map_type = f64, scale = 3.637978807091713e-12)]
+    omega_dot_semicircles: i32,
+
+    /// Almanac satellite clock bias (in seconds)
+    #[ubx(map_type = f64, scale = 9.5367431640625e-07)]
+    a0_s: i16,
+
+    /// Almanac satellite clock rate (in seconds per second)
+    #[ubx(map_type = f64, scale = 3.637978807091713e-12)]
+    a1: i16,
+
+    /// Reserved
+    reserved2: [u8; 4],
+}
@@ -0,0 +1,74 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-BDS HEALTH frame, health of all BeiDou satellites
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x03, fixed_payload_len = 68)]
+struct MgaBdsHealth {
+    /// Message type: 0x04 for this type
+    msg_type: u8,
+
+    /// Message version: 0x00 for this version
+    version: u8,
+
+    /// Reserved
+    reserved1: [u8; 2],
+
+    /// 9-bit health codes of satellites 1 to 30, as little endian u16, see `sv_health()`
+    health_code: [u8; 60],
+
+    /// Reserved
+    reserved2: [u8; 4],
+}
+
+macro_rules! impl_sv_health {
+    ($type:ty) => {
+        impl $type {
+            /// 9-bit health code of satellite `sv_id` (1 to 30)
+            pub fn sv_health(&self, sv_id: u8) -> Option<u16> {
+                let idx = usize::from(sv_id).checked_sub(1)?;
+                let code = self.0[4..64].chunks_exact(2).nth(idx)?;
+                Some(u16::from_le_bytes([code[0], code[1]]) & 0x1ff)
+            }
+        }
+    };
+}
+
+impl_sv_health!(MgaBdsHealthRef<'_>);
+impl_sv_health!(MgaBdsHealthOwned);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sv_health() {
+        let mut health_code = [0; 60];
+        health_code[2..4].copy_from_slice(&0x0100u16.to_le_bytes());
+        health_code[58..60].copy_from_slice(&0x01ffu16.to_le_bytes());
+        let bytes = MgaBdsHealthBuilder {
+            msg_type: 0x04,
+            version: 0x00,
+            reserved1: [0; 2],
+            health_code,
+            reserved2: [0; 4],
+        }
+        .into_packet_bytes();
+        let payload = &bytes[6..74];
+        assert!(MgaBdsHealthRef::validate(payload).is_ok());
+
+        let packet = MgaBdsHealthRef(payload);
+        assert_eq!(packet.sv_health(1), Some(0));
+        assert_eq!(packet.sv_health(2), Some(0x100));
+        assert_eq!(packet.sv_health(30), Some(0x1ff));
+        assert_eq!(packet.sv_health(31), None);
+    }
+}
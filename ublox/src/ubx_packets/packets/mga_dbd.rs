@@ -0,0 +1,135 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{error::ParserError, ubx_checksum, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv_send;
+
+/// Navigation database entry
+///
+/// The receiver dumps its navigation database as a sequence of these messages when
+/// polled with `UbxPacketRequest::request_for::<MgaDbd>()`. Sending the messages
+/// back unchanged restores the database, for example after a power cycle.
+/// [MgaDbdDump] collects a dump spanning many messages.
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x80, max_payload_len = 1024)]
+struct MgaDbd<'a> {
+    reserved1: [u8; 12],
+
+    /// Database content, not documented by u-blox
+    #[ubx(
+        map_type = &'a [u8],
+        from = MgaDbd::data_from_bytes,
+        into = MgaDbd::data_into_bytes,
+        get_as_ref,
+    )]
+    data: [u8; 0],
+}
+
+impl MgaDbd {
+    fn data_from_bytes(bytes: &[u8]) -> &[u8] {
+        bytes
+    }
+
+    fn data_into_bytes(bytes: &[u8]) -> &[u8] {
+        bytes
+    }
+}
+
+/// Navigation database dumped by the receiver
+///
+/// Push every `MgaDbd` message received after polling, the dump ends when
+/// the receiver stops sending them. Each message is restored as received, so
+/// the reserved bytes are kept as well.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use ublox::mga_dbd::MgaDbdDump;
+/// let mut dump = MgaDbdDump::new();
+/// // For each PacketRef::MgaDbd(packet) from the parser
+/// dump.push(&packet);
+///
+/// // Later, on boot
+/// let mut buffer = Vec::new();
+/// dump.extend_to(&mut buffer);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MgaDbdDump {
+    entries: Vec<Vec<u8>>,
+}
+
+#[cfg(feature = "alloc")]
+impl MgaDbdDump {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the next message of the dump
+    pub fn push(&mut self, packet: &MgaDbdRef<'_>) {
+        self.entries.push(packet.0.to_vec());
+    }
+
+    /// Number of messages in the dump
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Messages of the dump, in the order they must be sent back
+    pub fn iter(&self) -> impl Iterator<Item = MgaDbdBuilder<'_>> {
+        self.entries.iter().map(|payload| {
+            let mut reserved1 = [0; 12];
+            reserved1.copy_from_slice(&payload[..12]);
+            MgaDbdBuilder {
+                reserved1,
+                data: &payload[12..],
+            }
+        })
+    }
+
+    /// Writes the UBX frames restoring the dump
+    pub fn extend_to<T>(&self, out: &mut T)
+    where
+        T: core::iter::Extend<u8> + core::ops::DerefMut<Target = [u8]>,
+    {
+        for entry in self.iter() {
+            entry.extend_to(out);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_and_restore() {
+        let mut frames = [[0u8; 16], [0u8; 16]];
+        frames[0][0] = 0x01;
+        frames[0][12..].copy_from_slice(&[1, 2, 3, 4]);
+        frames[1][12..].copy_from_slice(&[5, 6, 7, 8]);
+
+        let mut dump = MgaDbdDump::new();
+        for payload in &frames {
+            assert!(MgaDbdRef::validate(payload).is_ok());
+            dump.push(&MgaDbdRef(payload));
+        }
+        assert_eq!(dump.len(), 2);
+
+        let mut buffer = Vec::new();
+        dump.extend_to(&mut buffer);
+        assert_eq!(buffer.len(), 2 * (8 + 16));
+        assert_eq!(&buffer[..6], &[0xb5, 0x62, 0x13, 0x80, 0x10, 0x00]);
+        assert_eq!(&buffer[6..22], &frames[0]);
+        assert_eq!(&buffer[30..46], &frames[1]);
+        assert_eq!(MgaDbdRef(&buffer[30..46]).data(), &[5, 6, 7, 8]);
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend, ubx_packet_recv, ubx_packet_send};
+
+/// Largest amount of data in a single `MgaFlashData` message
+pub const MGA_FLASH_DATA_MAX_LEN: usize = 512;
+
+/// Block of AssistNow Offline data to store in the receiver flash
+///
+/// The data is transferred in blocks numbered from 0, each one acknowledged
+/// with `MgaFlashAck` before the next is sent, and the transfer is ended with
+/// `MgaFlashStop`. Use [MgaFlashDataBuilder::blocks] to split the data.
+#[ubx_packet_send]
+#[ubx(class = 0x13, id = 0x21, max_payload_len = 518)] // 6 + 512
+struct MgaFlashData<'a> {
+    /// Message type (0x01 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Block sequence number, starting at 0
+    sequence: u16,
+
+    /// Payload size (bytes)
+    size: u16,
+
+    /// Data block
+    #[ubx(map_type = &'a [u8], into = MgaFlashData::data_into_bytes)]
+    data: [u8; 0],
+}
+
+impl MgaFlashData {
+    fn data_into_bytes(bytes: &[u8]) -> &[u8] {
+        bytes
+    }
+}
+
+impl<'a> MgaFlashDataBuilder<'a> {
+    /// Block `sequence` carrying `data`, which must not be longer than
+    /// [MGA_FLASH_DATA_MAX_LEN]
+    pub fn new(sequence: u16, data: &'a [u8]) -> Self {
+        debug_assert!(data.len() <= MGA_FLASH_DATA_MAX_LEN);
+        Self {
+            msg_type: 0x01,
+            version: 0x00,
+            sequence,
+            size: data.len() as u16,
+            data,
+        }
+    }
+
+    /// Splits `data` into numbered blocks, in the order they must be sent
+    pub fn blocks(data: &'a [u8]) -> impl Iterator<Item = Self> + 'a {
+        data.chunks(MGA_FLASH_DATA_MAX_LEN)
+            .enumerate()
+            .map(|(sequence, block)| Self::new(sequence as u16, block))
+    }
+}
+
+/// End of the transfer of AssistNow Offline data to the receiver flash
+#[ubx_packet_send]
+#[ubx(class = 0x13, id = 0x21, fixed_payload_len = 2)]
+struct MgaFlashStop {
+    /// Message type (0x02 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+}
+
+impl Default for MgaFlashStopBuilder {
+    fn default() -> Self {
+        Self {
+            msg_type: 0x02,
+            version: 0x00,
+        }
+    }
+}
+
+/// Acknowledgment of a block of data sent with `MgaFlashData`
+#[ubx_packet_recv]
+#[ubx(class = 0x13, id = 0x21, fixed_payload_len = 6)]
+struct MgaFlashAck {
+    /// Message type (0x03 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Type of acknowledgment, see [MgaFlashAckType]
+    #[ubx(map_type = MgaFlashAckType)]
+    ack: u8,
+
+    reserved1: u8,
+
+    /// Sequence number of the acknowledged block
+    sequence: u16,
+}
+
+/// Outcome of writing a block of data to flash
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MgaFlashAckType {
+    /// Block written, send the next one
+    Ack = 0,
+    /// Block not written, send it again
+    NackRetry = 1,
+    /// Transfer failed, give up
+    NackGiveUp = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn data_blocks() {
+        let data = [0x5a; 1000];
+        let blocks: alloc::vec::Vec<_> = MgaFlashDataBuilder::blocks(&data)
+            .map(|b| b.into_packet_vec())
+            .collect();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(
+            &blocks[0][..12],
+            &[0xb5, 0x62, 0x13, 0x21, 0x06, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02]
+        );
+        assert_eq!(blocks[0].len(), 8 + 6 + 512);
+        assert_eq!(
+            &blocks[1][..12],
+            &[0xb5, 0x62, 0x13, 0x21, 0xee, 0x01, 0x01, 0x00, 0x01, 0x00, 0xe8, 0x01]
+        );
+        assert_eq!(blocks[1].len(), 8 + 6 + 488);
+    }
+
+    #[test]
+    fn ack() {
+        let payload = [0x03, 0x00, 0x01, 0x00, 0x04, 0x00];
+        assert!(MgaFlashAckRef::validate(&payload).is_ok());
+        let packet = MgaFlashAckRef(&payload);
+        assert_eq!(packet.ack(), MgaFlashAckType::NackRetry);
+        assert_eq!(packet.sequence(), 4);
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, ubx_packets::packets::ScaleBack, MemWriter, MemWriterError,
+    UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-GAL ALM frame, almanac of a single Galileo satellite
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x02, fixed_payload_len = 32)]
+struct MgaGalAlm {
+    /// Message type: 0x02 for this type
+    msg_type: u8,
+
+    /// Message version: 0x00 for this version
+    version: u8,
+
+    /// Galileo satellite identifier
+    sv_id: u8,
+
+    /// Reserved
+    reserved1: u8,
+
+    /// Almanac issue of data
+    ioda: u8,
+
+    /// Almanac reference week number
+    alm_wna: u8,
+
+    /// Almanac reference time (in seconds)
+    #[ubx(map_type = f64, scale = 600.0)]
+    toa_s: u16,
+
+    /// Difference with respect to the square root of the nominal semi-major axis
+    /// (in square root of meters)
+    #[ubx(map_type = f64, scale = 0.001953125)]
+    delta_sqrt_a: i16,
+
+    /// Eccentricity
+    #[ubx(map_type = f64, scale = 1.52587890625e-05)]
+    e: u16,
+
+    /// Inclination at reference time relative to 56 degrees (in semi-circles)
+    #[ubx(map_type = f64, scale = 6.103515625e-05)]
+    delta_i_semicircles: i16,
+
+    /// Right ascension (in semi-circles)
+    #[ubx(map_type = f64, scale = 3.0517578125e-05)]
+    omega0_semicircles: i16,
+
+    /// Rate of change of right ascension (in semi-circles per second)
+    #[ubx(map_type = f64, scale = 1.1641532182693481e-10)]
+    omega_dot_semicircles: i16,
+
+    /// Argument of perigee (in semi-circles)
+    #[ubx(map_type = f64, scale = 3.0517578125e-05)]
+    omega_semicircles: i16,
+
+    /// Satellite mean anomaly at reference time (in semi-circles)
+    #[ubx(map_type = f64, scale = 3.0517578125e-05)]
+    m0_semicircles: i16,
+
+    /// Satellite clock correction bias (in seconds)
+    #[ubx(map_type = f64, scale = 1.9073486328125e-06)]
+    af0_s: i16,
+
+    /// Satellite clock correction linear term (in seconds per second)
+    #[ubx(map_type = f64, scale = 3.637978807091713e-12)]
+    af1: i16,
+
+    /// Satellite E1-B signal health status
+    health_e1b: u8,
+
+    /// Satellite E5b signal health status
+    health_e5b: u8,
+
+    /// Satellite E5a signal health status
+    health_e5a: u8,
+
+    /// Reserved
+    reserved2: [u8; 3],
+}
@@ -0,0 +1,120 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, ubx_packets::packets::ScaleBack, MemWriter, MemWriterError,
+    UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-GLO ALM frame, almanac of a single GLONASS satellite
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x06, fixed_payload_len = 36)]
+struct MgaGloAlm {
+    /// Message type: 0x02 for this type
+    msg_type: u8,
+
+    /// Message version: 0x00 for this version
+    version: u8,
+
+    /// GLONASS satellite identifier
+    sv_id: u8,
+
+    /// Reserved
+    reserved1: u8,
+
+    /// Reference calendar day number of the almanac within the four-year period
+    n: u16,
+
+    /// Type of GLONASS satellite (1 indicates GLONASS-M)
+    m: u8,
+
+    /// Unhealthy flag, 0 indicates non-operability of the satellite
+    c: u8,
+
+    /// Coarse time correction to GLONASS time (in seconds)
+    #[ubx(map_type = f64, scale = 3.814697265625e-06)]
+    tau_s: i16,
+
+    /// Eccentricity
+    #[ubx(map_type = f64, scale = 9.5367431640625e-07)]
+    epsilon: u16,
+
+    /// Longitude of the first ascending node within the day (in semi-circles)
+    #[ubx(map_type = f64, scale = 9.5367431640625e-07)]
+    lambda_semicircles: i32,
+
+    /// Correction to the mean value of inclination (in semi-circles)
+    #[ubx(map_type = f64, scale = 9.5367431640625e-07)]
+    delta_i_semicircles: i32,
+
+    /// Time of the first ascending node passage (in seconds)
+    #[ubx(map_type = f64, scale = 0.03125)]
+    t_lambda_s: u32,
+
+    /// Correction to the mean value of the Draconian period
+    /// (in seconds per orbital period)
+    #[ubx(map_type = f64, scale = 0.001953125)]
+    delta_t: i32,
+
+    /// Rate of change of the Draconian period
+    /// (in seconds per squared orbital period)
+    #[ubx(map_type = f64, scale = 6.103515625e-05)]
+    delta_dt: i8,
+
+    /// Carrier frequency number
+    h: i8,
+
+    /// Argument of perigee (in semi-circles)
+    #[ubx(map_type = f64, scale = 3.0517578125e-05)]
+    omega_semicircles: i16,
+
+    /// Reserved
+    reserved2: [u8; 4],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let bytes = MgaGloAlmBuilder {
+            msg_type: 0x02,
+            version: 0x00,
+            sv_id: 11,
+            reserved1: 0,
+            n: 732,
+            m: 1,
+            c: 1,
+            tau_s: -3.814697265625e-05,
+            epsilon: 0.00125,
+            lambda_semicircles: -0.5,
+            delta_i_semicircles: 0.0125,
+            t_lambda_s: 33_000.5,
+            delta_t: -2655.5,
+            delta_dt: 0.0006103515625,
+            h: -7,
+            omega_semicircles: 0.25,
+            reserved2: [0; 4],
+        }
+        .into_packet_bytes();
+        let payload = &bytes[6..42];
+        assert!(MgaGloAlmRef::validate(payload).is_ok());
+
+        let packet = MgaGloAlmRef(payload);
+        assert_eq!(packet.sv_id(), 11);
+        assert_eq!(packet.n(), 732);
+        assert_eq!(packet.h(), -7);
+        assert_eq!(packet.tau_s(), -3.814697265625e-05);
+        assert_eq!(packet.lambda_semicircles(), -0.5);
+        assert_eq!(packet.t_lambda_s(), 33_000.5);
+        assert_eq!(packet.delta_t(), -2655.5);
+        assert_eq!(packet.delta_dt(), 0.0006103515625);
+        assert_eq!(packet.omega_semicircles(), 0.25);
+        assert!((packet.epsilon() - 0.00125).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,45 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-GPS HEALTH frame, health of all GPS satellites
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x00, fixed_payload_len = 40)]
+struct MgaGpsHealth {
+    /// Message type: 0x04 for this type
+    msg_type: u8,
+
+    /// Message version: 0x00 for this version
+    version: u8,
+
+    /// Reserved
+    reserved1: [u8; 2],
+
+    /// Health codes of satellites 1 to 32, see `sv_health()`
+    health_code: [u8; 32],
+
+    /// Reserved
+    reserved2: [u8; 4],
+}
+
+macro_rules! impl_sv_health {
+    ($type:ty) => {
+        impl $type {
+            /// 6-bit health code of satellite `sv_id` (1 to 32)
+            pub fn sv_health(&self, sv_id: u8) -> Option<u8> {
+                let idx = usize::from(sv_id).checked_sub(1)?;
+                self.0[4..36].get(idx).map(|code| code & 0x3f)
+            }
+        }
+    };
+}
+
+impl_sv_health!(MgaGpsHealthRef<'_>);
+impl_sv_health!(MgaGpsHealthOwned);
@@ -0,0 +1,74 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, ubx_packets::packets::ScaleBack, MemWriter, MemWriterError,
+    UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-QZSS ALM frame, almanac of a single satellite
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x05, fixed_payload_len = 36)]
+struct MgaQzssAlm {
+    /// Message type: 0x02 for this type
+    msg_type: u8,
+
+    /// Message version: 0x00 for this version
+    version: u8,
+
+    /// QZSS satellite identifier
+    sv_id: u8,
+
+    /// Satellite health
+    sv_health: u8,
+
+    /// Eccentricity
+    #[ubx(map_type = f64, scale = 4.76837158203125e-07)]
+    e: u16,
+
+    /// Reference week number of the almanac
+    alm_wna: u8,
+
+    /// Reference time of the almanac (in seconds)
+    #[ubx(map_type = f64, scale = 4096.0)]
+    toa_s: u8,
+
+    /// Correction to the inclination angle (in semi-circles)
+    #[ubx(map_type = f64, scale = 1.9073486328125e-06)]
+    delta_i_semicircles: i16,
+
+    /// Rate of right ascension (in semi-circles per second)
+    #[ubx(map_type = f64, scale = 3.637978807091713e-12)]
+    omega_dot_semicircles: i16,
+
+    /// Square root of the semi-major axis (in square root of meters)
+    #[ubx(map_type = f64, scale = 0.00048828125)]
+    sqrt_a: u32,
+
+    /// Longitude of ascending node (in semi-circles)
+    #[ubx(map_type = f64, scale = 1.1920928955078125e-07)]
+    omega0_semicircles: i32,
+
+    /// Argument of perigee (in semi-circles)
+    #[ubx(map_type = f64, scale = 1.1920928955078125e-07)]
+    omega_semicircles: i32,
+
+    /// Mean anomaly at reference time (in semi-circles)
+    #[ubx(map_type = f64, scale = 1.1920928955078125e-07)]
+    m0_semicircles: i32,
+
+    /// Time polynomial coefficient 0 (in seconds)
+    #[ubx(map_type = f64, scale = 9.5367431640625e-07)]
+    af0_s: i16,
+
+    /// Time polynomial coefficient 1 (in seconds per second)
+    #[ubx(map_type = f64, scale = 3.637978807091713e-12)]
+    af1: i16,
+
+    /// Reserved
+    reserved1: [u8; 4],
+}
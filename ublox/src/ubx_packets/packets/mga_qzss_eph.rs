@@ -0,0 +1,154 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, ubx_packets::packets::ScaleBack, MemWriter, MemWriterError,
+    UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-QZSS EPH frame, ephemeris of a single QZSS satellite
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x05, fixed_payload_len = 68)]
+struct MgaQzssEph {
+    /// Message type (0x01 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// QZSS satellite identifier
+    sv_id: u8,
+
+    /// Reserved
+    reserved1: u8,
+
+    /// Fit interval flag
+    fit_interval: u8,
+
+    /// URA index
+    ura_index: u8,
+
+    /// Satellite health
+    sv_health: u8,
+
+    /// Group delay differential (in seconds)
+    #[ubx(map_type = f64, scale = 4.656612873077393e-10)]
+    tgd_s: i8,
+
+    /// Issue of data, clock
+    iodc: u16,
+
+    /// Clock data reference time (in seconds)
+    #[ubx(map_type = f64, scale = 16.0)]
+    toc_s: u16,
+
+    /// Reserved
+    reserved2: u8,
+
+    /// af2 correction term (in seconds per squared second)
+    #[ubx(map_type = f64, scale = 2.7755575615628914e-17)]
+    af2: i8,
+
+    /// af1 correction term (in seconds per second)
+    #[ubx(map_type = f64, scale = 1.1368683772161603e-13)]
+    af1: i16,
+
+    /// af0 correction term (in seconds)
+    #[ubx(map_type = f64, scale = 4.656612873077393e-10)]
+    af0_s: i32,
+
+    /// Crs (in meters)
+    #[ubx(map_type = f64, scale = 0.03125)]
+    crs_m: i16,
+
+    /// Mean motion difference from computed value (in semi-circles per second)
+    #[ubx(map_type = f64, scale = 1.1368683772161603e-13)]
+    delta_n_semicircles: i16,
+
+    /// Mean anomaly at reference time (in semi-circles)
+    #[ubx(map_type = f64, scale = 4.656612873077393e-10)]
+    m0_semicircles: i32,
+
+    /// Cuc (in radians)
+    #[ubx(map_type = f64, scale = 1.862645149230957e-09)]
+    cuc_rad: i16,
+
+    /// Cus (in radians)
+    #[ubx(map_type = f64, scale = 1.862645149230957e-09)]
+    cus_rad: i16,
+
+    /// Eccentricity
+    #[ubx(map_type = f64, scale = 1.1641532182693481e-10)]
+    e: u32,
+
+    /// Square root of the semi-major axis (in square root of meters)
+    #[ubx(map_type = f64, scale = 1.9073486328125e-06)]
+    sqrt_a: u32,
+
+    /// Reference time of ephemeris (in seconds)
+    #[ubx(map_type = f64, scale = 16.0)]
+    toe_s: u16,
+
+    /// Cic (in radians)
+    #[ubx(map_type = f64, scale = 1.862645149230957e-09)]
+    cic_rad: i16,
+
+    /// Longitude of ascending node of orbit plane at weekly epoch (in semi-circles)
+    #[ubx(map_type = f64, scale = 4.656612873077393e-10)]
+    omega0_semicircles: i32,
+
+    /// Cis (in radians)
+    #[ubx(map_type = f64, scale = 1.862645149230957e-09)]
+    cis_rad: i16,
+
+    /// Crc (in meters)
+    #[ubx(map_type = f64, scale = 0.03125)]
+    crc_m: i16,
+
+    /// Inclination angle at reference time (in semi-circles)
+    #[ubx(map_type = f64, scale = 4.656612873077393e-10)]
+    i0_semicircles: i32,
+
+    /// Argument of perigee (in semi-circles)
+    #[ubx(map_type = f64, scale = 4.656612873077393e-10)]
+    omega_semicircles: i32,
+
+    /// Rate of right ascension (in semi-circles per second)
+    #[ubx(map_type = f64, scale = 1.1368683772161603e-13)]
+    omega_dot_semicircles: i32,
+
+    /// Rate of inclination angle (in semi-circles per second)
+    #[ubx(map_type = f64, scale = 1.1368683772161603e-13)]
+    idot_semicircles: i16,
+
+    /// Reserved
+    reserved3: [u8; 2],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaled_accessors() {
+        let mut payload = [0u8; 68];
+        payload[0] = 0x01;
+        payload[2] = 2; // sv_id
+        payload[10..12].copy_from_slice(&25_200u16.to_le_bytes()); // toc
+        payload[32..36].copy_from_slice(&(1u32 << 29).to_le_bytes()); // e
+        payload[36..40].copy_from_slice(&(6493u32 << 19).to_le_bytes()); // sqrt_a
+        payload[50..52].copy_from_slice(&(-64i16).to_le_bytes()); // crc
+        assert!(MgaQzssEphRef::validate(&payload).is_ok());
+
+        let packet = MgaQzssEphRef(&payload);
+        assert_eq!(packet.sv_id(), 2);
+        assert_eq!(packet.toc_s(), 403_200.0);
+        assert_eq!(packet.e(), 0.0625);
+        assert_eq!(packet.sqrt_a(), 6493.0);
+        assert_eq!(packet.crc_m(), -2.0);
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-QZSS HEALTH frame, health of all QZSS satellites
+#[ubx_packet_recv_send]
+#[ubx(class = 0x13, id = 0x05, fixed_payload_len = 12)]
+struct MgaQzssHealth {
+    /// Message type: 0x04 for this type
+    msg_type: u8,
+
+    /// Message version: 0x00 for this version
+    version: u8,
+
+    /// Reserved
+    reserved1: [u8; 2],
+
+    /// Health codes of satellites 1 to 5, see `sv_health()`
+    health_code: [u8; 5],
+
+    /// Reserved
+    reserved2: [u8; 3],
+}
+
+macro_rules! impl_sv_health {
+    ($type:ty) => {
+        impl $type {
+            /// 6-bit health code of satellite `sv_id` (1 to 5)
+            pub fn sv_health(&self, sv_id: u8) -> Option<u8> {
+                let idx = usize::from(sv_id).checked_sub(1)?;
+                self.0[4..9].get(idx).map(|code| code & 0x3f)
+            }
+        }
+    };
+}
+
+impl_sv_health!(MgaQzssHealthRef<'_>);
+impl_sv_health!(MgaQzssHealthOwned);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sv_health() {
+        let bytes = MgaQzssHealthBuilder {
+            msg_type: 0x04,
+            version: 0x00,
+            reserved1: [0; 2],
+            health_code: [0x00, 0x3f, 0x00, 0x00, 0x01],
+            reserved2: [0; 3],
+        }
+        .into_packet_bytes();
+        let packet = MgaQzssHealthRef(&bytes[6..18]);
+        assert_eq!(packet.sv_health(1), Some(0));
+        assert_eq!(packet.sv_health(2), Some(0x3f));
+        assert_eq!(packet.sv_health(5), Some(1));
+        assert_eq!(packet.sv_health(0), None);
+        assert_eq!(packet.sv_health(6), None);
+    }
+}
@@ -0,0 +1,173 @@
+use bitflags::bitflags;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv};
+
+/// Synchronization manager status
+///
+/// Reports the state of the oscillators and time/frequency sources handled by the
+/// synchronization manager configured with `CfgSmgr`.
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x2e, fixed_payload_len = 16)]
+struct MonSmgr {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    reserved1: [u8; 3],
+
+    /// Time of the week (ms)
+    itow: u32,
+
+    /// State of the internal oscillator, see [MonSmgrOscStatus]
+    #[ubx(map_type = MonSmgrOscStatus)]
+    int_osc: u16,
+
+    /// State of the external oscillator, see [MonSmgrOscStatus]
+    #[ubx(map_type = MonSmgrOscStatus)]
+    ext_osc: u16,
+
+    /// Disciplining source identifier, see [MonSmgrDiscSource]
+    #[ubx(map_type = MonSmgrDiscSource)]
+    disc_src: u8,
+
+    /// GNSS status, see [MonSmgrGnssFlags]
+    #[ubx(map_type = MonSmgrGnssFlags)]
+    gnss: u8,
+
+    /// EXTINT0 status, see [MonSmgrExtIntFlags]
+    #[ubx(map_type = MonSmgrExtIntFlags)]
+    ext_int0: u8,
+
+    /// EXTINT1 status, see [MonSmgrExtIntFlags]
+    #[ubx(map_type = MonSmgrExtIntFlags)]
+    ext_int1: u8,
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct MonSmgrOscStatus(u16);
+
+impl MonSmgrOscStatus {
+    /// State of the oscillator
+    pub fn state(self) -> MonSmgrOscState {
+        MonSmgrOscState::from((self.0 & 0x0f) as u8)
+    }
+
+    /// Oscillator gain is calibrated
+    pub fn calibrated(self) -> bool {
+        self.0 & 0x10 != 0
+    }
+
+    /// Signal is disciplined
+    pub fn disciplined(self) -> bool {
+        self.0 & 0x20 != 0
+    }
+
+    const fn from(x: u16) -> Self {
+        Self(x)
+    }
+}
+
+impl fmt::Debug for MonSmgrOscStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MonSmgrOscStatus")
+            .field("state", &self.state())
+            .field("calibrated", &self.calibrated())
+            .field("disciplined", &self.disciplined())
+            .finish()
+    }
+}
+
+/// State of an oscillator handled by the synchronization manager
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MonSmgrOscState {
+    Autonomous = 0,
+    Calibrating = 1,
+    /// Oscillator is steered by the host
+    HostControlled = 2,
+    Idle = 3,
+}
+
+/// Source used to discipline the oscillators
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MonSmgrDiscSource {
+    InternalOscillator = 0,
+    Gnss = 1,
+    ExtInt0 = 2,
+    ExtInt1 = 3,
+    /// Internal oscillator measured by the host
+    HostInternalOscillator = 4,
+    /// External oscillator measured by the host
+    HostExternalOscillator = 5,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// GNSS status for `MonSmgr`
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct MonSmgrGnssFlags: u8 {
+        /// GNSS is present
+        const GNSS_AVAIL = 0x01;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// EXTINT status for `MonSmgr`
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct MonSmgrExtIntFlags: u8 {
+        /// Frequency source is available on the input
+        const FREQ_AVAIL = 0x01;
+        /// Time source is available on the input
+        const TIME_AVAIL = 0x02;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oscillator_state() {
+        let payload = [
+            0x00, 0x00, 0x00, 0x00, // version, reserved1
+            0xe8, 0x03, 0x00, 0x00, // itow
+            0x30, 0x00, // int_osc: autonomous, calibrated, disciplined
+            0x02, 0x00, // ext_osc: host controlled
+            0x01, // disc_src: GNSS
+            0x01, // gnss
+            0x03, // ext_int0
+            0x00, // ext_int1
+        ];
+        assert!(MonSmgrRef::validate(&payload).is_ok());
+
+        let packet = MonSmgrRef(&payload);
+        assert_eq!(packet.itow(), 1_000);
+        let int_osc = packet.int_osc();
+        assert_eq!(int_osc.state(), MonSmgrOscState::Autonomous);
+        assert!(int_osc.calibrated() && int_osc.disciplined());
+        assert_eq!(packet.ext_osc().state(), MonSmgrOscState::HostControlled);
+        assert_eq!(packet.disc_src(), MonSmgrDiscSource::Gnss);
+        assert!(packet.gnss().contains(MonSmgrGnssFlags::GNSS_AVAIL));
+        assert_eq!(
+            packet.ext_int0(),
+            MonSmgrExtIntFlags::FREQ_AVAIL | MonSmgrExtIntFlags::TIME_AVAIL
+        );
+        assert!(packet.ext_int1().is_empty());
+    }
+}
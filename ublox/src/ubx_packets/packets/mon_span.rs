@@ -76,11 +76,7 @@ impl<'a> MonSpanRfBlock<'a> {
     /// Returns the number of valid spectrum points.
     /// Calculated as span / res when res > 0.
     pub fn num_points(&self) -> Option<u32> {
-        if self.res > 0 {
-            Some(self.span / self.res)
-        } else {
-            None
-        }
+        self.span.checked_div(self.res)
     }
 }
 
@@ -0,0 +1,73 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_packet_recv};
+
+/// Current system performance information
+#[ubx_packet_recv]
+#[ubx(class = 0x0a, id = 0x39, fixed_payload_len = 24)]
+struct MonSys {
+    /// Message version (0x01 for this version)
+    msg_ver: u8,
+
+    /// Boot type, see [MonSysBootType]
+    #[ubx(map_type = MonSysBootType)]
+    boot_type: u8,
+
+    /// CPU load (%)
+    cpu_load: u8,
+
+    /// Maximum CPU load since startup (%)
+    cpu_load_max: u8,
+
+    /// Memory usage (%)
+    mem_usage: u8,
+
+    /// Maximum memory usage since startup (%)
+    mem_usage_max: u8,
+
+    /// I/O buffer usage (%)
+    io_usage: u8,
+
+    /// Maximum I/O buffer usage since startup (%)
+    io_usage_max: u8,
+
+    /// Time since startup (s)
+    run_time: u32,
+
+    /// Number of notices since startup
+    notice_count: u16,
+
+    /// Number of warnings since startup
+    warn_count: u16,
+
+    /// Number of errors since startup
+    error_count: u16,
+
+    /// Temperature (°C)
+    temp_value: i8,
+
+    reserved0: [u8; 5],
+}
+
+/// Cause of the last receiver startup
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MonSysBootType {
+    Unknown = 0,
+    ColdStart = 1,
+    Watchdog = 2,
+    HardwareReset = 3,
+    HardwareBackup = 4,
+    SoftwareBackup = 5,
+    SoftwareReset = 6,
+    VioFail = 7,
+    VddXFail = 8,
+    VddRfFail = 9,
+    VCoreHighFail = 10,
+}
@@ -43,6 +43,13 @@ pub(crate) fn is_cstr_valid(bytes: &[u8]) -> bool {
     core::str::from_utf8(&bytes[0..null_pos]).is_ok()
 }
 
+/// Text of a fixed size string field, padded with NUL bytes but not
+/// necessarily NUL terminated
+pub(crate) fn fixed_size_str(bytes: &[u8]) -> Option<&str> {
+    let end = bytes.iter().position(|x| *x == 0).unwrap_or(bytes.len());
+    core::str::from_utf8(&bytes[..end]).ok()
+}
+
 #[derive(Debug, Clone)]
 pub struct MonVerExtensionIter<'a> {
     data: &'a [u8],
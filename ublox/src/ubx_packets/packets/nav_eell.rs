@@ -0,0 +1,32 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv;
+
+/// Position error ellipse parameters
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x3d, fixed_payload_len = 16)]
+struct NavEell {
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    reserved1: u8,
+
+    /// Orientation of semi-major axis of error ellipse (degrees from true north)
+    #[ubx(map_type = f64, scale = 1e-2, alias = err_ellipse_orientation)]
+    err_ellipse_orient: u16,
+
+    /// Semi-major axis of error ellipse \[m\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = err_ellipse_major_meters)]
+    err_ellipse_major: u32,
+
+    /// Semi-minor axis of error ellipse \[m\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = err_ellipse_minor_meters)]
+    err_ellipse_minor: u32,
+}
@@ -0,0 +1,135 @@
+#[allow(unused_imports, reason = "It is only unused in some feature sets")]
+use crate::FieldIter;
+#[cfg(feature = "serde")]
+use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_packet_recv};
+
+/// Geofencing status
+///
+/// Reports the state of each geofence configured with UBX-CFG-GEOFENCE
+/// (or the CFG-GEOFENCE-* configuration keys) and their combined state.
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x39, max_payload_len = 16)] // 8 + 2 * 4
+struct NavGeofence {
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Geofencing status, see [NavGeofenceStatus]
+    #[ubx(map_type = NavGeofenceStatus)]
+    status: u8,
+
+    /// Number of geofences
+    num_fences: u8,
+
+    /// Combined (logical OR) state of all geofences
+    #[ubx(map_type = GeofenceState)]
+    comb_state: u8,
+
+    #[ubx(
+        map_type = NavGeofenceIter,
+        from = NavGeofenceIter::new,
+        is_valid = NavGeofenceIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    fences: [u8; 0],
+}
+
+/// Geofencing status
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NavGeofenceStatus {
+    /// Geofencing not available or not reliable
+    NotAvailable = 0,
+    /// Geofencing active
+    Active = 1,
+}
+
+/// State of a geofence, or of the combination of all geofences
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GeofenceState {
+    Unknown = 0,
+    Inside = 1,
+    Outside = 2,
+}
+
+#[derive(Debug, Clone)]
+pub struct NavGeofenceIter<'a>(core::slice::ChunksExact<'a, u8>);
+
+impl<'a> NavGeofenceIter<'a> {
+    const BLOCK_SIZE: usize = 2;
+
+    fn new(data: &'a [u8]) -> Self {
+        Self(data.chunks_exact(Self::BLOCK_SIZE))
+    }
+
+    #[allow(dead_code, reason = "Used by ubx_packet_recv macro for validation")]
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len().is_multiple_of(Self::BLOCK_SIZE)
+    }
+}
+
+impl<'a> core::iter::Iterator for NavGeofenceIter<'a> {
+    type Item = NavGeofenceFenceRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(NavGeofenceFenceRef)
+    }
+}
+
+/// State of a single geofence
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x39, fixed_payload_len = 2)]
+struct NavGeofenceFence {
+    /// Geofence state
+    #[ubx(map_type = GeofenceState)]
+    state: u8,
+
+    /// Geofence ID (0 = not available), reserved on older firmware
+    id: u8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn per_fence_states() {
+        let payload = [
+            0x10, 0x27, 0x00, 0x00, // itow
+            0x00, // version
+            0x01, // status
+            0x03, // num_fences
+            0x01, // comb_state
+            0x01, 0x01, // fence 1: inside
+            0x02, 0x02, // fence 2: outside
+            0x00, 0x03, // fence 3: unknown
+        ];
+        assert!(NavGeofenceRef::validate(&payload).is_ok());
+
+        let packet = NavGeofenceRef(&payload);
+        assert_eq!(packet.itow(), 10_000);
+        assert_eq!(packet.status(), NavGeofenceStatus::Active);
+        assert_eq!(packet.comb_state(), GeofenceState::Inside);
+
+        let expected = [
+            (1, GeofenceState::Inside),
+            (2, GeofenceState::Outside),
+            (3, GeofenceState::Unknown),
+        ];
+        assert!(packet
+            .fences()
+            .map(|f| (f.id(), f.state()))
+            .eq(expected.into_iter()));
+    }
+}
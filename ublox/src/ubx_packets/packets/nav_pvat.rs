@@ -0,0 +1,248 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use bitflags::bitflags;
+use chrono::{DateTime, Utc};
+use core::convert::TryFrom;
+
+use super::nav_pvt::common::{NavPvtFlags2, NavPvtValidFlags};
+use crate::error::DateTimeError;
+use crate::ubx_packets::types::{PositionLLA, ToDateTime, ToLLA, ToVelocity, Velocity};
+use crate::{error::ParserError, GnssFixType, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// Navigation Position Velocity Attitude Time Solution
+///
+/// Only available on dead-reckoning (ADR/UDR) products.
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x17, fixed_payload_len = 116)]
+struct NavPvat {
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Validity flags, see [NavPvtValidFlags]
+    #[ubx(map_type = NavPvtValidFlags)]
+    valid: u8,
+
+    /// Year (UTC)
+    year: u16,
+    /// Month, range 1..12 (UTC)
+    month: u8,
+    /// Day of month, range 1..31 (UTC)
+    day: u8,
+    /// Hour of day, range 0..23 (UTC)
+    hour: u8,
+    /// Minute of hour, range 0..59 (UTC)
+    min: u8,
+    /// Seconds of minute, range 0..60 (UTC)
+    sec: u8,
+
+    reserved0: u8,
+
+    reserved1: [u8; 2],
+
+    /// Time accuracy estimate in nanoseconds (UTC)
+    time_accuracy: u32,
+
+    /// Fraction of second, range -1e9 .. 1e9 (UTC)
+    nanosec: i32,
+
+    /// GNSS Fix Type, see [GnssFixType]
+    #[ubx(map_type = GnssFixType)]
+    fix_type: u8,
+
+    /// Fix status flags, see [NavPvatFlags]
+    #[ubx(map_type = NavPvatFlags)]
+    flags: u8,
+
+    /// Additional flags, see [NavPvtFlags2]
+    #[ubx(map_type = NavPvtFlags2)]
+    flags2: u8,
+
+    /// Number of satellites used in Nav Solution
+    num_satellites: u8,
+
+    /// Longitude in \[deg\]
+    #[ubx(map_type = f64, scale = 1e-7, alias = longitude)]
+    lon: i32,
+
+    /// Latitude in \[deg\]
+    #[ubx(map_type = f64, scale = 1e-7, alias = latitude)]
+    lat: i32,
+
+    /// Height above reference ellipsoid in \[m\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = height_above_ellipsoid)]
+    height: i32,
+
+    /// Height above Mean Sea Level in \[m\]
+    #[ubx(map_type = f64, scale = 1e-3)]
+    height_msl: i32,
+
+    /// Horizontal accuracy in \[m\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = horizontal_accuracy)]
+    h_acc: u32,
+
+    /// Vertical accuracy in \[m\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = vertical_accuracy)]
+    v_acc: u32,
+
+    /// Velocity North component \[m/s\]
+    #[ubx(map_type = f64, scale = 1e-3)]
+    vel_north: i32,
+
+    /// Velocity East component \[m/s\]
+    #[ubx(map_type = f64, scale = 1e-3)]
+    vel_east: i32,
+
+    /// Velocity Down component \[m/s\]
+    #[ubx(map_type = f64, scale = 1e-3)]
+    vel_down: i32,
+
+    /// Ground speed \[m/s\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = ground_speed_2d)]
+    g_speed: i32,
+
+    /// Speed Accuracy Estimate \[m/s\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = speed_accuracy)]
+    s_acc: u32,
+
+    /// Vehicle roll \[deg\], only valid when [VEH_ROLL_VALID](NavPvatFlags::VEH_ROLL_VALID) is set
+    #[ubx(map_type = f64, scale = 1e-5, alias = vehicle_roll)]
+    veh_roll: i32,
+
+    /// Vehicle pitch \[deg\], only valid when [VEH_PITCH_VALID](NavPvatFlags::VEH_PITCH_VALID) is set
+    #[ubx(map_type = f64, scale = 1e-5, alias = vehicle_pitch)]
+    veh_pitch: i32,
+
+    /// Vehicle heading \[deg\], only valid when [VEH_HEADING_VALID](NavPvatFlags::VEH_HEADING_VALID) is set
+    #[ubx(map_type = f64, scale = 1e-5, alias = vehicle_heading)]
+    veh_heading: i32,
+
+    /// Motion heading \[deg\]
+    #[ubx(map_type = f64, scale = 1e-5, alias = heading_motion)]
+    mot_heading: i32,
+
+    /// Vehicle roll accuracy \[deg\]
+    #[ubx(map_type = f64, scale = 1e-2, alias = roll_accuracy)]
+    acc_roll: u16,
+
+    /// Vehicle pitch accuracy \[deg\]
+    #[ubx(map_type = f64, scale = 1e-2, alias = pitch_accuracy)]
+    acc_pitch: u16,
+
+    /// Vehicle heading accuracy \[deg\]
+    #[ubx(map_type = f64, scale = 1e-2, alias = heading_accuracy)]
+    acc_heading: u16,
+
+    /// Magnetic declination \[deg\]
+    #[ubx(map_type = f64, scale = 1e-2, alias = magnetic_declination)]
+    mag_dec: i16,
+
+    /// Magnetic declination accuracy \[deg\]
+    #[ubx(map_type = f64, scale = 1e-2, alias = magnetic_declination_accuracy)]
+    mag_acc: u16,
+
+    /// Orientation of semi-major axis of error ellipse (degrees from true north)
+    #[ubx(map_type = f64, scale = 1e-2, alias = err_ellipse_orientation)]
+    err_ellipse_orient: u16,
+
+    /// Semi-major axis of error ellipse \[m\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = err_ellipse_major_meters)]
+    err_ellipse_major: u32,
+
+    /// Semi-minor axis of error ellipse \[m\]
+    #[ubx(map_type = f64, scale = 1e-3, alias = err_ellipse_minor_meters)]
+    err_ellipse_minor: u32,
+
+    reserved2: [u8; 4],
+
+    reserved3: [u8; 4],
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Fix status flags for `NavPvat`
+    #[derive(Debug)]
+    pub struct NavPvatFlags: u8 {
+        /// 1 = Position and velocity valid and within DOP and accuracy masks
+        const GNSS_FIX_OK = 0x01;
+        /// 1 = Differential corrections were applied
+        const DIFF_SOLN = 0x02;
+        /// 1 = Vehicle roll is valid
+        const VEH_ROLL_VALID = 0x08;
+        /// 1 = Vehicle pitch is valid
+        const VEH_PITCH_VALID = 0x10;
+        /// 1 = Vehicle heading is valid
+        const VEH_HEADING_VALID = 0x20;
+        /// 1 = Carrier phase range solution with floating ambiguities
+        const CARR_SOLN_FLOAT = 0x40;
+        /// 1 = Carrier phase range solution with fixed ambiguities
+        const CARR_SOLN_FIXED = 0x80;
+    }
+}
+
+macro_rules! impl_to_lla {
+    ($type:ty) => {
+        impl ToLLA for $type {
+            fn to_lla(&self) -> PositionLLA {
+                PositionLLA {
+                    lon: self.longitude(),
+                    lat: self.latitude(),
+                    alt: self.height_msl(),
+                }
+            }
+        }
+    };
+}
+
+impl_to_lla!(NavPvatRef<'_>);
+impl_to_lla!(NavPvatOwned);
+
+macro_rules! impl_to_velocity {
+    ($type:ty) => {
+        impl ToVelocity for $type {
+            fn to_velocity(&self) -> Velocity {
+                Velocity {
+                    speed: self.ground_speed_2d(),
+                    heading: self.heading_motion(),
+                }
+            }
+        }
+    };
+}
+
+impl_to_velocity!(NavPvatRef<'_>);
+impl_to_velocity!(NavPvatOwned);
+
+macro_rules! impl_to_date_time {
+    ($type:ty) => {
+        impl ToDateTime for $type {
+            fn to_datetime(&self) -> Result<DateTime<Utc>, DateTimeError> {
+                crate::ubx_packets::types::datetime_from_components(
+                    self.year(),
+                    self.month(),
+                    self.day(),
+                    self.hour(),
+                    self.min(),
+                    self.sec(),
+                    self.nanosec(),
+                )
+            }
+        }
+        impl TryFrom<&$type> for DateTime<Utc> {
+            type Error = DateTimeError;
+            fn try_from(sol: &$type) -> Result<Self, Self::Error> {
+                sol.to_datetime()
+            }
+        }
+    };
+}
+
+impl_to_date_time!(NavPvatRef<'_>);
+impl_to_date_time!(NavPvatOwned);
@@ -0,0 +1,240 @@
+#[allow(unused_imports, reason = "It is only unused in some feature sets")]
+use crate::FieldIter;
+use bitflags::bitflags;
+#[cfg(feature = "serde")]
+use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv};
+
+/// SBAS status data
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x32, max_payload_len = 3072)] // 12 + 255 * 12
+struct NavSbas {
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// PRN number of the GEO where correction and integrity data is used from
+    geo: u8,
+
+    /// SBAS mode, see [SbasMode]
+    #[ubx(map_type = SbasMode)]
+    mode: u8,
+
+    /// SBAS system of the GEO, see [SbasSystem]
+    #[ubx(map_type = SbasSystem)]
+    sys: u8,
+
+    /// SBAS services available from the GEO, see [SbasService]
+    #[ubx(map_type = SbasService)]
+    service: u8,
+
+    /// Number of SV data following
+    cnt: u8,
+
+    /// SBAS status flags, see [NavSbasStatusFlags]
+    #[ubx(map_type = NavSbasStatusFlags)]
+    status_flags: u8,
+
+    reserved1: [u8; 2],
+
+    #[ubx(
+        map_type = NavSbasIter,
+        from = NavSbasIter::new,
+        is_valid = NavSbasIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    svs: [u8; 0],
+}
+
+/// SBAS mode
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SbasMode {
+    Disabled = 0,
+    EnabledIntegrity = 1,
+    EnabledTestMode = 3,
+}
+
+/// SBAS system
+///
+/// The receiver reports it as a signed byte where -1 (0xFF) means unknown
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SbasSystem {
+    Unknown = 0xFF,
+    Waas = 0,
+    Egnos = 1,
+    Msas = 2,
+    Gagan = 3,
+    Gps = 16,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// SBAS services available
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct SbasService: u8 {
+        /// GEO may be used as ranging source
+        const RANGING = 0x01;
+        /// GEO is providing correction data
+        const CORRECTIONS = 0x02;
+        /// GEO is providing integrity
+        const INTEGRITY = 0x04;
+        /// GEO is in test mode
+        const TEST_MODE = 0x08;
+        /// Problem with signal or broadcast data indicated
+        const BAD = 0x10;
+    }
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct NavSbasStatusFlags(u8);
+
+impl NavSbasStatusFlags {
+    /// SBAS integrity usage
+    pub fn integrity_used(self) -> SbasIntegrityUsed {
+        match self.0 & 0x3 {
+            1 => SbasIntegrityUsed::NotUsed,
+            2 => SbasIntegrityUsed::Used,
+            _ => SbasIntegrityUsed::Unknown,
+        }
+    }
+
+    const fn from(x: u8) -> Self {
+        Self(x)
+    }
+}
+
+impl core::fmt::Debug for NavSbasStatusFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NavSbasStatusFlags")
+            .field("integrity_used", &self.integrity_used())
+            .finish()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SbasIntegrityUsed {
+    Unknown,
+    /// Integrity information is not available or SBAS integrity is not enabled
+    NotUsed,
+    /// Receiver uses only GPS satellites for which integrity information is available
+    Used,
+}
+
+#[derive(Debug, Clone)]
+pub struct NavSbasIter<'a>(core::slice::ChunksExact<'a, u8>);
+
+impl<'a> NavSbasIter<'a> {
+    const BLOCK_SIZE: usize = 12;
+
+    fn new(data: &'a [u8]) -> Self {
+        Self(data.chunks_exact(Self::BLOCK_SIZE))
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len().is_multiple_of(Self::BLOCK_SIZE)
+    }
+}
+
+impl<'a> core::iter::Iterator for NavSbasIter<'a> {
+    type Item = NavSbasSvRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(NavSbasSvRef)
+    }
+}
+
+/// SBAS data of a single SV
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x32, fixed_payload_len = 12)]
+struct NavSbasSv {
+    /// SV ID
+    svid: u8,
+
+    /// Flags for this SV (reserved on newer firmware)
+    flags: u8,
+
+    /// Monitoring status
+    udre: u8,
+
+    /// System, see [SbasSystem]
+    #[ubx(map_type = SbasSystem)]
+    sv_sys: u8,
+
+    /// Services available, see [SbasService]
+    #[ubx(map_type = SbasService)]
+    sv_service: u8,
+
+    reserved2: u8,
+
+    /// Pseudo range correction (m)
+    #[ubx(map_type = f64, scale = 1e-2, alias = prc_meters)]
+    prc: i16,
+
+    reserved3: [u8; 2],
+
+    /// Ionosphere correction (m)
+    #[ubx(map_type = f64, scale = 1e-2, alias = ic_meters)]
+    ic: i16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sv_blocks() {
+        let payload = [
+            0xe8, 0x03, 0x00, 0x00, // itow
+            0x83, // geo: PRN 131
+            0x01, // mode
+            0x00, // sys: WAAS
+            0x07, // service
+            0x02, // cnt
+            0x02, // status_flags
+            0x00, 0x00, // reserved1
+            0x05, 0x00, 0x0f, 0xff, 0x00, 0x00, 0x9c, 0xff, 0x00, 0x00, 0x2c, 0x01, // sv 5
+            0x83, 0x00, 0x01, 0x01, 0x13, 0x00, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, // sv 131
+        ];
+        assert!(NavSbasRef::validate(&payload).is_ok());
+        assert!(NavSbasRef::validate(&payload[..23]).is_err());
+
+        let packet = NavSbasRef(&payload);
+        assert_eq!(packet.geo(), 131);
+        assert_eq!(packet.mode(), SbasMode::EnabledIntegrity);
+        assert_eq!(packet.sys(), SbasSystem::Waas);
+        assert_eq!(
+            packet.service(),
+            SbasService::RANGING | SbasService::CORRECTIONS | SbasService::INTEGRITY
+        );
+        assert_eq!(
+            packet.status_flags().integrity_used(),
+            SbasIntegrityUsed::Used
+        );
+
+        let mut svs = packet.svs();
+        let sv = svs.next().unwrap();
+        assert_eq!(sv.svid(), 5);
+        assert_eq!(sv.sv_sys(), SbasSystem::Unknown);
+        assert_eq!(sv.sv_service(), SbasService::empty());
+        assert!((sv.prc_meters() + 1.0).abs() < 1e-9);
+        assert!((sv.ic_meters() - 3.0).abs() < 1e-9);
+
+        let sv = svs.next().unwrap();
+        assert_eq!(sv.svid(), 131);
+        assert_eq!(sv.sv_sys(), SbasSystem::Egnos);
+        assert!(sv.sv_service().contains(SbasService::BAD));
+        assert!(svs.next().is_none());
+    }
+}
@@ -0,0 +1,146 @@
+#[allow(unused_imports, reason = "It is only unused in some feature sets")]
+use crate::FieldIter;
+use bitflags::bitflags;
+#[cfg(feature = "serde")]
+use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// QZSS L1C/B SLAS (Sub-meter Level Augmentation Service) status data
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x42, max_payload_len = 2060)] // 20 + 255 * 8
+struct NavSlas {
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    reserved1: [u8; 3],
+
+    /// Longitude of the used ground monitoring station (deg)
+    #[ubx(map_type = f64, scale = 1e-3, alias = gms_lon_degrees)]
+    gms_lon: i32,
+
+    /// Latitude of the used ground monitoring station (deg)
+    #[ubx(map_type = f64, scale = 1e-3, alias = gms_lat_degrees)]
+    gms_lat: i32,
+
+    /// Code of the used ground monitoring station according to the QZSS SLAS interface specification
+    gms_code: u8,
+
+    /// Satellite identifier of the QZS/GEO whose correction data is used
+    qzss_sv_id: u8,
+
+    /// SLAS service flags, see [NavSlasServiceFlags]
+    #[ubx(map_type = NavSlasServiceFlags)]
+    service_flags: u8,
+
+    /// Number of pseudorange corrections following
+    cnt: u8,
+
+    #[ubx(
+        map_type = NavSlasIter,
+        from = NavSlasIter::new,
+        is_valid = NavSlasIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    corrections: [u8; 0],
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// SLAS service flags
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct NavSlasServiceFlags: u8 {
+        /// Ground monitoring station available
+        const GM_AVAILABLE = 0x01;
+        /// Correction providing QZSS SV available
+        const QZSS_SV_AVAILABLE = 0x02;
+        /// Currently used QZSS SV in test mode
+        const TEST_MODE = 0x04;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NavSlasIter<'a>(core::slice::ChunksExact<'a, u8>);
+
+impl<'a> NavSlasIter<'a> {
+    const BLOCK_SIZE: usize = 8;
+
+    fn new(data: &'a [u8]) -> Self {
+        Self(data.chunks_exact(Self::BLOCK_SIZE))
+    }
+
+    #[allow(dead_code, reason = "Used by ubx_packet_recv macro for validation")]
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len().is_multiple_of(Self::BLOCK_SIZE)
+    }
+}
+
+impl<'a> core::iter::Iterator for NavSlasIter<'a> {
+    type Item = NavSlasCorrectionRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(NavSlasCorrectionRef)
+    }
+}
+
+/// Pseudorange correction of a single SV
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x42, fixed_payload_len = 8)]
+struct NavSlasCorrection {
+    /// GNSS identifier
+    gnss_id: u8,
+
+    /// Satellite identifier
+    sv_id: u8,
+
+    reserved2: u8,
+
+    reserved3: [u8; 3],
+
+    /// Pseudorange correction (m)
+    #[ubx(map_type = f64, scale = 1e-2, alias = prc_meters)]
+    prc: i16,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corrections() {
+        let payload = [
+            0xe8, 0x03, 0x00, 0x00, // itow
+            0x00, // version
+            0x00, 0x00, 0x00, // reserved1
+            0xb0, 0x2a, 0x02, 0x00, // gms_lon: 142.0 deg
+            0x98, 0x85, 0x00, 0x00, // gms_lat: 34.2 deg
+            0x05, // gms_code
+            0xc3, // qzss_sv_id: 195
+            0x03, // service_flags
+            0x01, // cnt
+            0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0xce, 0xff, // GPS 10, -0.5 m
+        ];
+        assert!(NavSlasRef::validate(&payload).is_ok());
+
+        let packet = NavSlasRef(&payload);
+        assert!((packet.gms_lon_degrees() - 142.0).abs() < 1e-9);
+        assert!((packet.gms_lat_degrees() - 34.2).abs() < 1e-9);
+        assert_eq!(packet.qzss_sv_id(), 195);
+        assert_eq!(
+            packet.service_flags(),
+            NavSlasServiceFlags::GM_AVAILABLE | NavSlasServiceFlags::QZSS_SV_AVAILABLE
+        );
+
+        let mut corrections = packet.corrections();
+        let c = corrections.next().unwrap();
+        assert_eq!((c.gnss_id(), c.sv_id()), (0, 10));
+        assert!((c.prc_meters() + 0.5).abs() < 1e-9);
+        assert!(corrections.next().is_none());
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::ubx_packets::types::{PositionECEF, ToECEF};
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv;
+
+/// Survey-in data
+///
+/// Reports the progress of a survey-in started through `CfgTmode3`
+/// (or the CFG-TMODE-* configuration keys) on high precision base stations.
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x3B, fixed_payload_len = 40)]
+struct NavSvin {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    reserved1: [u8; 3],
+
+    /// GPS time of week of the navigation epoch (ms)
+    itow: u32,
+
+    /// Passed survey-in observation time (s)
+    dur: u32,
+
+    /// Current survey-in mean position ECEF X coordinate
+    ///
+    /// Raw UBX payload unit: centimeters (cm).
+    /// This crate exposes this value as meters (m) via `scale = 1e-2`.
+    #[ubx(map_type = f64, scale = 1e-2, alias = mean_x_meters)]
+    mean_x: i32,
+
+    /// Current survey-in mean position ECEF Y coordinate
+    ///
+    /// Raw UBX payload unit: centimeters (cm).
+    /// This crate exposes this value as meters (m) via `scale = 1e-2`.
+    #[ubx(map_type = f64, scale = 1e-2, alias = mean_y_meters)]
+    mean_y: i32,
+
+    /// Current survey-in mean position ECEF Z coordinate
+    ///
+    /// Raw UBX payload unit: centimeters (cm).
+    /// This crate exposes this value as meters (m) via `scale = 1e-2`.
+    #[ubx(map_type = f64, scale = 1e-2, alias = mean_z_meters)]
+    mean_z: i32,
+
+    /// High precision component of the mean ECEF X coordinate
+    /// Must be in the range -99..+99
+    /// Precise coordinate in cm = mean_x + (mean_x_hp * 1e-2).
+    #[ubx(map_type = f64, scale = 1e-4, alias = mean_x_hp_meters)]
+    mean_x_hp: i8,
+
+    /// High precision component of the mean ECEF Y coordinate
+    /// Must be in the range -99..+99
+    /// Precise coordinate in cm = mean_y + (mean_y_hp * 1e-2).
+    #[ubx(map_type = f64, scale = 1e-4, alias = mean_y_hp_meters)]
+    mean_y_hp: i8,
+
+    /// High precision component of the mean ECEF Z coordinate
+    /// Must be in the range -99..+99
+    /// Precise coordinate in cm = mean_z + (mean_z_hp * 1e-2).
+    #[ubx(map_type = f64, scale = 1e-4, alias = mean_z_hp_meters)]
+    mean_z_hp: i8,
+
+    reserved2: u8,
+
+    /// Current survey-in mean position accuracy
+    ///
+    /// Raw UBX payload unit: 0.1 mm.
+    /// This crate exposes this value as meters (m) via `scale = 1e-4`.
+    #[ubx(map_type = f64, scale = 1e-4, alias = mean_acc_meters)]
+    mean_acc: u32,
+
+    /// Number of position observations used during survey-in
+    obs: u32,
+
+    /// Survey-in position validity flag
+    #[ubx(map_type = bool, from = flag_is_set)]
+    valid: u8,
+
+    /// Survey-in in progress flag
+    #[ubx(map_type = bool, from = flag_is_set)]
+    active: u8,
+
+    reserved3: [u8; 2],
+}
+
+fn flag_is_set(raw: u8) -> bool {
+    raw == 1
+}
+
+macro_rules! impl_to_ecef {
+    ($type:ty) => {
+        impl ToECEF for $type {
+            fn to_ecef(&self) -> PositionECEF {
+                PositionECEF {
+                    x: self.mean_x_meters() + self.mean_x_hp_meters(),
+                    y: self.mean_y_meters() + self.mean_y_hp_meters(),
+                    z: self.mean_z_meters() + self.mean_z_hp_meters(),
+                }
+            }
+        }
+    };
+}
+
+impl_to_ecef!(NavSvinRef<'_>);
+impl_to_ecef!(NavSvinOwned);
@@ -0,0 +1,94 @@
+use bitflags::bitflags;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::error::DateTimeError;
+use crate::ubx_packets::types::{
+    datetime_from_week_tow, gnss_time_from_week_tow, ToDateTime, BEIDOU_EPOCH,
+};
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// BeiDou time solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x24, fixed_payload_len = 20)]
+struct NavTimeBds {
+    /// GPS time of week of the navigation epoch (ms).
+    itow: u32,
+
+    /// BeiDou time of week (rounded seconds) (s).
+    sow: u32,
+
+    /// Fractional part of the BeiDou time of week (range: +/- 500000000) (ns).
+    f_sow: i32,
+
+    /// BeiDou week number.
+    week: i16,
+
+    /// BeiDou leap seconds (BeiDou-UTC) (s).
+    leap_s: i8,
+
+    /// Validity Flags.
+    #[ubx(map_type = NavTimeBdsFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns).
+    t_acc: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTimeBds`
+    #[derive(Default, Debug)]
+    pub struct NavTimeBdsFlags: u8 {
+        /// Valid BeiDou time of week (sow + f_sow).
+        const SOW_VALID = 1;
+        /// Valid BeiDou week number.
+        const WEEK_VALID = 2;
+        /// Valid BeiDou leap seconds.
+        const LEAP_S_VALID = 4;
+    }
+}
+
+macro_rules! impl_to_date_time {
+    ($type:ty) => {
+        impl $type {
+            /// Date and time of the navigation epoch in BeiDou Time (BDT),
+            /// counted from the start of BeiDou week 0 (2006-01-01).
+            ///
+            /// Only meaningful when [NavTimeBdsFlags::SOW_VALID] and
+            /// [NavTimeBdsFlags::WEEK_VALID] are set.
+            pub fn bdt(&self) -> Result<NaiveDateTime, DateTimeError> {
+                gnss_time_from_week_tow(BEIDOU_EPOCH, self.week(), self.sow(), self.f_sow())
+            }
+        }
+
+        impl ToDateTime for $type {
+            fn to_datetime(&self) -> Result<DateTime<Utc>, DateTimeError> {
+                datetime_from_week_tow(
+                    BEIDOU_EPOCH,
+                    self.week(),
+                    self.sow(),
+                    self.f_sow(),
+                    self.leap_s(),
+                )
+            }
+        }
+
+        impl TryFrom<&$type> for DateTime<Utc> {
+            type Error = DateTimeError;
+            fn try_from(sol: &$type) -> Result<Self, Self::Error> {
+                sol.to_datetime()
+            }
+        }
+    };
+}
+
+impl_to_date_time!(NavTimeBdsRef<'_>);
+impl_to_date_time!(NavTimeBdsOwned);
@@ -0,0 +1,99 @@
+use bitflags::bitflags;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::error::DateTimeError;
+use crate::ubx_packets::types::{
+    datetime_from_week_tow, gnss_time_from_week_tow, ToDateTime, GALILEO_EPOCH,
+};
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// Galileo time solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x25, fixed_payload_len = 20)]
+struct NavTimeGal {
+    /// GPS time of week of the navigation epoch (ms).
+    itow: u32,
+
+    /// Galileo time of week (rounded seconds) (s).
+    gal_tow: u32,
+
+    /// Fractional part of the Galileo time of week (range: +/- 500000000) (ns).
+    f_gal_tow: i32,
+
+    /// Galileo week number.
+    gal_wno: i16,
+
+    /// Galileo leap seconds (Galileo-UTC) (s).
+    leap_s: i8,
+
+    /// Validity Flags.
+    #[ubx(map_type = NavTimeGalFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns).
+    t_acc: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTimeGal`
+    #[derive(Default, Debug)]
+    pub struct NavTimeGalFlags: u8 {
+        /// Valid Galileo time of week (gal_tow + f_gal_tow).
+        const GAL_TOW_VALID = 1;
+        /// Valid Galileo week number.
+        const GAL_WNO_VALID = 2;
+        /// Valid Galileo leap seconds.
+        const LEAP_S_VALID = 4;
+    }
+}
+
+macro_rules! impl_to_date_time {
+    ($type:ty) => {
+        impl $type {
+            /// Date and time of the navigation epoch in Galileo System Time (GST),
+            /// counted from the start of Galileo week 0 (1999-08-22).
+            ///
+            /// Only meaningful when [NavTimeGalFlags::GAL_TOW_VALID] and
+            /// [NavTimeGalFlags::GAL_WNO_VALID] are set.
+            pub fn gst(&self) -> Result<NaiveDateTime, DateTimeError> {
+                gnss_time_from_week_tow(
+                    GALILEO_EPOCH,
+                    self.gal_wno(),
+                    self.gal_tow(),
+                    self.f_gal_tow(),
+                )
+            }
+        }
+
+        impl ToDateTime for $type {
+            fn to_datetime(&self) -> Result<DateTime<Utc>, DateTimeError> {
+                datetime_from_week_tow(
+                    GALILEO_EPOCH,
+                    self.gal_wno(),
+                    self.gal_tow(),
+                    self.f_gal_tow(),
+                    self.leap_s(),
+                )
+            }
+        }
+
+        impl TryFrom<&$type> for DateTime<Utc> {
+            type Error = DateTimeError;
+            fn try_from(sol: &$type) -> Result<Self, Self::Error> {
+                sol.to_datetime()
+            }
+        }
+    };
+}
+
+impl_to_date_time!(NavTimeGalRef<'_>);
+impl_to_date_time!(NavTimeGalOwned);
@@ -0,0 +1,150 @@
+use bitflags::bitflags;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::error::DateTimeError;
+use crate::ubx_packets::types::ToDateTime;
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// GLONASS time solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x23, fixed_payload_len = 20)]
+struct NavTimeGlo {
+    /// GPS time of week of the navigation epoch (ms).
+    itow: u32,
+
+    /// GLONASS time of day (rounded seconds) (s).
+    tod: u32,
+
+    /// Fractional part of the GLONASS time of day (range: +/- 500000000) (ns).
+    f_tod: i32,
+
+    /// Current date (range: 1-1461), starting at 1 from the 1st of January
+    /// of the last leap year, see also `n4`.
+    nt: u16,
+
+    /// Four-year interval number starting from 1996 (1 = 1996, 2 = 2000, 3 = 2004...).
+    n4: u8,
+
+    /// Validity Flags.
+    #[ubx(map_type = NavTimeGloFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns).
+    t_acc: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTimeGlo`
+    #[derive(Default, Debug)]
+    pub struct NavTimeGloFlags: u8 {
+        /// Valid GLONASS time of day (tod + f_tod).
+        const TOD_VALID = 1;
+        /// Valid GLONASS date (nt + n4).
+        const DATE_VALID = 2;
+    }
+}
+
+/// GLONASS time is kept in Moscow time (UTC + 3h)
+const GLONASS_UTC_OFFSET_S: i64 = 3 * 3600;
+const SECONDS_PER_DAY: u32 = 86_400;
+const DAYS_PER_FOUR_YEARS: u16 = 1461;
+
+fn glonass_time(n4: u8, nt: u16, tod: u32, f_tod: i32) -> Result<NaiveDateTime, DateTimeError> {
+    if n4 == 0 || nt == 0 || nt > DAYS_PER_FOUR_YEARS {
+        return Err(DateTimeError::InvalidDate);
+    }
+    if tod >= SECONDS_PER_DAY {
+        return Err(DateTimeError::InvalidTime);
+    }
+    if (f_tod.wrapping_abs() as u32) >= 1_000_000_000 {
+        return Err(DateTimeError::InvalidNanoseconds);
+    }
+
+    let interval_start = NaiveDate::from_ymd_opt(1996 + 4 * (i32::from(n4) - 1), 1, 1)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .ok_or(DateTimeError::InvalidDate)?;
+
+    Ok(interval_start
+        + chrono::Duration::days(i64::from(nt) - 1)
+        + chrono::Duration::seconds(i64::from(tod))
+        + chrono::Duration::nanoseconds(i64::from(f_tod)))
+}
+
+macro_rules! impl_to_date_time {
+    ($type:ty) => {
+        impl $type {
+            /// Date and time of the navigation epoch in GLONASS time (Moscow time, UTC + 3h),
+            /// built from the four-year interval `n4` and day number `nt`.
+            ///
+            /// Only meaningful when [NavTimeGloFlags::TOD_VALID] and
+            /// [NavTimeGloFlags::DATE_VALID] are set.
+            pub fn glonass_time(&self) -> Result<NaiveDateTime, DateTimeError> {
+                glonass_time(self.n4(), self.nt(), self.tod(), self.f_tod())
+            }
+        }
+
+        impl ToDateTime for $type {
+            fn to_datetime(&self) -> Result<DateTime<Utc>, DateTimeError> {
+                // GLONASS time follows UTC leap seconds, only the time zone differs
+                let dt = self.glonass_time()? - chrono::Duration::seconds(GLONASS_UTC_OFFSET_S);
+                Ok(DateTime::from_naive_utc_and_offset(dt, Utc))
+            }
+        }
+
+        impl TryFrom<&$type> for DateTime<Utc> {
+            type Error = DateTimeError;
+            fn try_from(sol: &$type) -> Result<Self, Self::Error> {
+                sol.to_datetime()
+            }
+        }
+    };
+}
+
+impl_to_date_time!(NavTimeGloRef<'_>);
+impl_to_date_time!(NavTimeGloOwned);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glonass_date_and_moscow_offset() {
+        // 2024-02-08 02:59:42.5 Moscow time, fourth year interval 8 (2024..2027), day 39
+        let mut payload = [0u8; 20];
+        payload[4..8].copy_from_slice(&10_782u32.to_le_bytes());
+        payload[8..12].copy_from_slice(&500_000_000i32.to_le_bytes());
+        payload[12..14].copy_from_slice(&39u16.to_le_bytes());
+        payload[14] = 8;
+        payload[15] = 0x03;
+        let packet = NavTimeGloRef(&payload);
+
+        assert!(packet.valid().contains(NavTimeGloFlags::DATE_VALID));
+        let utc = DateTime::<Utc>::try_from(&packet).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2024, 2, 7)
+            .and_then(|date| date.and_hms_milli_opt(23, 59, 42, 500))
+            .unwrap()
+            .and_utc();
+        assert_eq!(utc, expected);
+    }
+
+    #[test]
+    fn glonass_invalid_day() {
+        assert!(matches!(
+            glonass_time(8, 0, 0, 0),
+            Err(DateTimeError::InvalidDate)
+        ));
+        assert!(matches!(
+            glonass_time(8, 1462, 0, 0),
+            Err(DateTimeError::InvalidDate)
+        ));
+    }
+}
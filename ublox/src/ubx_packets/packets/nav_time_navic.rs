@@ -0,0 +1,99 @@
+use bitflags::bitflags;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::error::DateTimeError;
+use crate::ubx_packets::types::{
+    datetime_from_week_tow, gnss_time_from_week_tow, ToDateTime, NAVIC_EPOCH,
+};
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// NavIC time solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x63, fixed_payload_len = 20)]
+struct NavTimeNavic {
+    /// GPS time of week of the navigation epoch (ms).
+    itow: u32,
+
+    /// NavIC time of week (rounded seconds) (s).
+    navic_tow: u32,
+
+    /// Fractional part of the NavIC time of week (range: +/- 500000000) (ns).
+    f_navic_tow: i32,
+
+    /// NavIC week number.
+    navic_wno: i16,
+
+    /// NavIC leap seconds (NavIC-UTC) (s).
+    leap_s: i8,
+
+    /// Validity Flags.
+    #[ubx(map_type = NavTimeNavicFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns).
+    t_acc: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTimeNavic`
+    #[derive(Default, Debug)]
+    pub struct NavTimeNavicFlags: u8 {
+        /// Valid NavIC time of week (navic_tow + f_navic_tow).
+        const NAVIC_TOW_VALID = 1;
+        /// Valid NavIC week number.
+        const NAVIC_WNO_VALID = 2;
+        /// Valid NavIC leap seconds.
+        const LEAP_S_VALID = 4;
+    }
+}
+
+macro_rules! impl_to_date_time {
+    ($type:ty) => {
+        impl $type {
+            /// Date and time of the navigation epoch in NavIC System Time,
+            /// counted from the start of NavIC week 0 (1999-08-22).
+            ///
+            /// Only meaningful when [NavTimeNavicFlags::NAVIC_TOW_VALID] and
+            /// [NavTimeNavicFlags::NAVIC_WNO_VALID] are set.
+            pub fn navic_time(&self) -> Result<NaiveDateTime, DateTimeError> {
+                gnss_time_from_week_tow(
+                    NAVIC_EPOCH,
+                    self.navic_wno(),
+                    self.navic_tow(),
+                    self.f_navic_tow(),
+                )
+            }
+        }
+
+        impl ToDateTime for $type {
+            fn to_datetime(&self) -> Result<DateTime<Utc>, DateTimeError> {
+                datetime_from_week_tow(
+                    NAVIC_EPOCH,
+                    self.navic_wno(),
+                    self.navic_tow(),
+                    self.f_navic_tow(),
+                    self.leap_s(),
+                )
+            }
+        }
+
+        impl TryFrom<&$type> for DateTime<Utc> {
+            type Error = DateTimeError;
+            fn try_from(sol: &$type) -> Result<Self, Self::Error> {
+                sol.to_datetime()
+            }
+        }
+    };
+}
+
+impl_to_date_time!(NavTimeNavicRef<'_>);
+impl_to_date_time!(NavTimeNavicOwned);
@@ -0,0 +1,99 @@
+use bitflags::bitflags;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use core::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::error::DateTimeError;
+use crate::ubx_packets::types::{
+    datetime_from_week_tow, gnss_time_from_week_tow, ToDateTime, GPS_EPOCH,
+};
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// QZSS time solution
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x27, fixed_payload_len = 20)]
+struct NavTimeQzss {
+    /// GPS time of week of the navigation epoch (ms).
+    itow: u32,
+
+    /// QZSS time of week (rounded seconds) (s).
+    qzss_tow: u32,
+
+    /// Fractional part of the QZSS time of week (range: +/- 500000000) (ns).
+    f_qzss_tow: i32,
+
+    /// QZSS week number.
+    qzss_wno: i16,
+
+    /// QZSS leap seconds (QZSS-UTC) (s).
+    leap_s: i8,
+
+    /// Validity Flags.
+    #[ubx(map_type = NavTimeQzssFlags)]
+    valid: u8,
+
+    /// Time Accuracy Estimate (ns).
+    t_acc: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTimeQzss`
+    #[derive(Default, Debug)]
+    pub struct NavTimeQzssFlags: u8 {
+        /// Valid QZSS time of week (qzss_tow + f_qzss_tow).
+        const QZSS_TOW_VALID = 1;
+        /// Valid QZSS week number.
+        const QZSS_WNO_VALID = 2;
+        /// Valid QZSS leap seconds.
+        const LEAP_S_VALID = 4;
+    }
+}
+
+macro_rules! impl_to_date_time {
+    ($type:ty) => {
+        impl $type {
+            /// Date and time of the navigation epoch in QZSS System Time (QZSST).
+            /// QZSST is aligned with GPS time, so weeks are counted from 1980-01-06.
+            ///
+            /// Only meaningful when [NavTimeQzssFlags::QZSS_TOW_VALID] and
+            /// [NavTimeQzssFlags::QZSS_WNO_VALID] are set.
+            pub fn qzsst(&self) -> Result<NaiveDateTime, DateTimeError> {
+                gnss_time_from_week_tow(
+                    GPS_EPOCH,
+                    self.qzss_wno(),
+                    self.qzss_tow(),
+                    self.f_qzss_tow(),
+                )
+            }
+        }
+
+        impl ToDateTime for $type {
+            fn to_datetime(&self) -> Result<DateTime<Utc>, DateTimeError> {
+                datetime_from_week_tow(
+                    GPS_EPOCH,
+                    self.qzss_wno(),
+                    self.qzss_tow(),
+                    self.f_qzss_tow(),
+                    self.leap_s(),
+                )
+            }
+        }
+
+        impl TryFrom<&$type> for DateTime<Utc> {
+            type Error = DateTimeError;
+            fn try_from(sol: &$type) -> Result<Self, Self::Error> {
+                sol.to_datetime()
+            }
+        }
+    };
+}
+
+impl_to_date_time!(NavTimeQzssRef<'_>);
+impl_to_date_time!(NavTimeQzssOwned);
@@ -6,21 +6,32 @@ use super::{SerializeMap, SerializeUbxPacketFields};
 use crate::ubx_packets::packets::{
     ack::{AckAck, AckAckOwned, AckAckRef, AckNak, AckNakOwned, AckNakRef},
     cfg_ant::{CfgAnt, CfgAntOwned, CfgAntRef},
+    cfg_dat::{CfgDat, CfgDatOwned, CfgDatRef},
+    cfg_dgnss::{CfgDgnss, CfgDgnssOwned, CfgDgnssRef},
     cfg_esf_alg::{CfgEsfAlg, CfgEsfAlgOwned, CfgEsfAlgRef},
     cfg_esf_wt::{CfgEsfWt, CfgEsfWtOwned, CfgEsfWtRef},
+    cfg_geofence::{CfgGeofence, CfgGeofenceOwned, CfgGeofenceRef},
     cfg_gnss::{CfgGnss, CfgGnssOwned, CfgGnssRef},
+    cfg_hnr::{CfgHnr, CfgHnrOwned, CfgHnrRef},
     cfg_inf::{CfgInf, CfgInfOwned, CfgInfRef},
     cfg_itfm::{CfgItfm, CfgItfmOwned, CfgItfmRef},
+    cfg_logfilter::{CfgLogfilter, CfgLogfilterOwned, CfgLogfilterRef},
     cfg_nav5::{CfgNav5, CfgNav5Owned, CfgNav5Ref},
+    cfg_nmea::{CfgNmea, CfgNmeaOwned, CfgNmeaRef},
     cfg_odo::{CfgOdo, CfgOdoOwned, CfgOdoRef},
+    cfg_pm2::{CfgPm2, CfgPm2Owned, CfgPm2Ref},
+    cfg_pms::{CfgPms, CfgPmsOwned, CfgPmsRef},
     cfg_prt::{
         CfgPrtI2c, CfgPrtI2cOwned, CfgPrtI2cRef, CfgPrtSpi, CfgPrtSpiOwned, CfgPrtSpiRef,
         CfgPrtUart, CfgPrtUartOwned, CfgPrtUartRef,
     },
+    cfg_rxm::{CfgRxm, CfgRxmOwned, CfgRxmRef},
+    cfg_sbas::{CfgSbas, CfgSbasOwned, CfgSbasRef},
     cfg_smgr::{CfgSmgr, CfgSmgrOwned, CfgSmgrRef},
     cfg_tmode2::{CfgTmode2, CfgTmode2Owned, CfgTmode2Ref},
     cfg_tmode3::{CfgTmode3, CfgTmode3Owned, CfgTmode3Ref},
     cfg_tp5::{CfgTp5, CfgTp5Owned, CfgTp5Ref},
+    cfg_usb::{CfgUsb, CfgUsbOwned, CfgUsbRef},
     esf_alg::{EsfAlg, EsfAlgOwned, EsfAlgRef},
     esf_ins::{EsfIns, EsfInsOwned, EsfInsRef},
     esf_meas::{EsfMeas, EsfMeasOwned, EsfMeasRef},
@@ -35,14 +46,26 @@ use crate::ubx_packets::packets::{
     inf_test::{InfTest, InfTestOwned, InfTestRef},
     inf_warning::{InfWarning, InfWarningOwned, InfWarningRef},
     mga_ack::{MgaAck, MgaAckOwned, MgaAckRef},
+    mga_ano::{MgaAno, MgaAnoOwned, MgaAnoRef},
+    mga_bds_alm::{MgaBdsAlm, MgaBdsAlmOwned, MgaBdsAlmRef},
     mga_bds_eph::{MgaBdsEph, MgaBdsEphOwned, MgaBdsEphRef},
+    mga_bds_health::{MgaBdsHealth, MgaBdsHealthOwned, MgaBdsHealthRef},
     mga_bds_utc::{MgaBdsUtc, MgaBdsUtcOwned, MgaBdsUtcRef},
+    mga_dbd::{MgaDbd, MgaDbdOwned, MgaDbdRef},
+    mga_flash::{MgaFlashAck, MgaFlashAckOwned, MgaFlashAckRef},
+    mga_gal_alm::{MgaGalAlm, MgaGalAlmOwned, MgaGalAlmRef},
     mga_gal_eph::{MgaGalEph, MgaGalEphOwned, MgaGalEphRef},
     mga_gal_time::{MgaGalTime, MgaGalTimeOwned, MgaGalTimeRef},
+    mga_glo_alm::{MgaGloAlm, MgaGloAlmOwned, MgaGloAlmRef},
     mga_glo_eph::{MgaGloEph, MgaGloEphOwned, MgaGloEphRef},
+    mga_gps_alm::{MgaGpsAlm, MgaGpsAlmOwned, MgaGpsAlmRef},
     mga_gps_eph::{MgaGpsEph, MgaGpsEphOwned, MgaGpsEphRef},
+    mga_gps_health::{MgaGpsHealth, MgaGpsHealthOwned, MgaGpsHealthRef},
     mga_gps_iono::{MgaGpsIono, MgaGpsIonoOwned, MgaGpsIonoRef},
     mga_gps_utc::{MgaGpsUtc, MgaGpsUtcOwned, MgaGpsUtcRef},
+    mga_qzss_alm::{MgaQzssAlm, MgaQzssAlmOwned, MgaQzssAlmRef},
+    mga_qzss_eph::{MgaQzssEph, MgaQzssEphOwned, MgaQzssEphRef},
+    mga_qzss_health::{MgaQzssHealth, MgaQzssHealthOwned, MgaQzssHealthRef},
     mon_gnss::{MonGnss, MonGnssOwned, MonGnssRef},
     mon_hw::{MonHw, MonHwOwned, MonHwRef},
     mon_hw2::{MonHw2, MonHw2Owned, MonHw2Ref},
@@ -51,11 +74,13 @@ use crate::ubx_packets::packets::{
     mon_patch::{MonPatch, MonPatchOwned, MonPatchRef},
     mon_rxbuf::{MonRxbuf, MonRxbufOwned, MonRxbufRef},
     mon_rxr::{MonRxr, MonRxrOwned, MonRxrRef},
+    mon_smgr::{MonSmgr, MonSmgrOwned, MonSmgrRef},
     mon_txbuf::{MonTxbuf, MonTxbufOwned, MonTxbufRef},
     mon_ver::{MonVer, MonVerOwned, MonVerRef},
     nav_att::{NavAtt, NavAttOwned, NavAttRef},
     nav_clock::{NavClock, NavClockOwned, NavClockRef},
     nav_dop::{NavDop, NavDopOwned, NavDopRef},
+    nav_geofence::{NavGeofence, NavGeofenceOwned, NavGeofenceRef},
     nav_hp_pos_ecef::{NavHpPosEcef, NavHpPosEcefOwned, NavHpPosEcefRef},
     nav_hp_pos_llh::{NavHpPosLlh, NavHpPosLlhOwned, NavHpPosLlhRef},
     nav_orb::{NavOrb, NavOrbOwned, NavOrbRef},
@@ -67,20 +92,34 @@ use crate::ubx_packets::packets::{
     nav_pvt::proto23::{NavPvt, NavPvtOwned, NavPvtRef},
     nav_rel_pos_ned::proto23::{NavRelPosNed, NavRelPosNedOwned, NavRelPosNedRef},
     nav_sat::{NavSat, NavSatOwned, NavSatRef},
+    nav_sbas::{NavSbas, NavSbasOwned, NavSbasRef},
+    nav_slas::{NavSlas, NavSlasOwned, NavSlasRef},
     nav_sol::{NavSol, NavSolOwned, NavSolRef},
     nav_status::{NavStatus, NavStatusOwned, NavStatusRef},
+    nav_time_bds::{NavTimeBds, NavTimeBdsOwned, NavTimeBdsRef},
+    nav_time_gal::{NavTimeGal, NavTimeGalOwned, NavTimeGalRef},
+    nav_time_glo::{NavTimeGlo, NavTimeGloOwned, NavTimeGloRef},
     nav_time_gps::{NavTimeGps, NavTimeGpsOwned, NavTimeGpsRef},
     nav_time_ls::{NavTimeLs, NavTimeLsOwned, NavTimeLsRef},
     nav_time_utc::{NavTimeUTC, NavTimeUTCOwned, NavTimeUTCRef},
     nav_vel_ned::{NavVelNed, NavVelNedOwned, NavVelNedRef},
+    rxm_measx::{RxmMeasx, RxmMeasxOwned, RxmMeasxRef},
     rxm_rawx::{RxmRawx, RxmRawxOwned, RxmRawxRef},
+    rxm_rlm::{
+        RxmRlmLong, RxmRlmLongOwned, RxmRlmLongRef, RxmRlmShort, RxmRlmShortOwned, RxmRlmShortRef,
+    },
     rxm_rtcm::{RxmRtcm, RxmRtcmOwned, RxmRtcmRef},
     rxm_sfrbx::{RxmSfrbx, RxmSfrbxOwned, RxmSfrbxRef},
+    sec_sign::{SecSign, SecSignOwned, SecSignRef},
     sec_uniq_id::{SecUniqId, SecUniqIdOwned, SecUniqIdRef},
+    tim_fchg::{TimFchg, TimFchgOwned, TimFchgRef},
+    tim_smeas::{TimSmeas, TimSmeasOwned, TimSmeasRef},
     tim_svin::{TimSvin, TimSvinOwned, TimSvinRef},
     tim_tm2::{TimTm2, TimTm2Owned, TimTm2Ref},
     tim_tos::{TimTos, TimTosOwned, TimTosRef},
     tim_tp::{TimTp, TimTpOwned, TimTpRef},
+    tim_vcocal::{TimVcocal, TimVcocalOwned, TimVcocalRef},
+    tim_vrfy::{TimVrfy, TimVrfyOwned, TimVrfyRef},
 };
 use crate::{ParserError, UbxPacketMeta, UbxUnknownPacketOwned, UbxUnknownPacketRef};
 use ublox_derive::define_recv_packets;
@@ -105,6 +144,17 @@ define_recv_packets!(
         CfgTmode2,
         CfgTmode3,
         CfgTp5,
+        CfgGeofence,
+        CfgSbas,
+        CfgPm2,
+        CfgRxm,
+        CfgPms,
+        CfgNmea,
+        CfgUsb,
+        CfgDat,
+        CfgDgnss,
+        CfgHnr,
+        CfgLogfilter,
         EsfAlg,
         EsfIns,
         EsfMeas,
@@ -128,6 +178,7 @@ define_recv_packets!(
         MonRxr,
         MonRxbuf,
         MonTxbuf,
+        MonSmgr,
         MgaAck,
         MgaGpsIono,
         MgaGpsEph,
@@ -137,6 +188,18 @@ define_recv_packets!(
         MgaGalEph,
         MgaGalTime,
         MgaGloEph,
+        MgaAno,
+        MgaDbd,
+        MgaFlashAck,
+        MgaGpsAlm,
+        MgaGpsHealth,
+        MgaGalAlm,
+        MgaBdsAlm,
+        MgaBdsHealth,
+        MgaGloAlm,
+        MgaQzssEph,
+        MgaQzssAlm,
+        MgaQzssHealth,
         NavAtt,
         NavClock,
         NavDop,
@@ -156,13 +219,27 @@ define_recv_packets!(
         NavTimeUTC,
         NavTimeLs,
         NavVelECEF,
+        NavTimeBds,
+        NavTimeGal,
+        NavTimeGlo,
+        NavGeofence,
+        NavSbas,
+        NavSlas,
         RxmRawx,
         RxmRtcm,
         RxmSfrbx,
+        RxmMeasx,
+        RxmRlmShort,
+        RxmRlmLong,
         SecUniqId,
+        SecSign,
         TimSvin,
         TimTp,
         TimTm2,
         TimTos,
+        TimVrfy,
+        TimSmeas,
+        TimFchg,
+        TimVcocal,
     }
 );
@@ -32,14 +32,26 @@ use crate::ubx_packets::packets::{
     inf_test::{InfTest, InfTestOwned, InfTestRef},
     inf_warning::{InfWarning, InfWarningOwned, InfWarningRef},
     mga_ack::{MgaAck, MgaAckOwned, MgaAckRef},
+    mga_ano::{MgaAno, MgaAnoOwned, MgaAnoRef},
+    mga_bds_alm::{MgaBdsAlm, MgaBdsAlmOwned, MgaBdsAlmRef},
     mga_bds_eph::{MgaBdsEph, MgaBdsEphOwned, MgaBdsEphRef},
+    mga_bds_health::{MgaBdsHealth, MgaBdsHealthOwned, MgaBdsHealthRef},
     mga_bds_utc::{MgaBdsUtc, MgaBdsUtcOwned, MgaBdsUtcRef},
+    mga_dbd::{MgaDbd, MgaDbdOwned, MgaDbdRef},
+    mga_flash::{MgaFlashAck, MgaFlashAckOwned, MgaFlashAckRef},
+    mga_gal_alm::{MgaGalAlm, MgaGalAlmOwned, MgaGalAlmRef},
     mga_gal_eph::{MgaGalEph, MgaGalEphOwned, MgaGalEphRef},
     mga_gal_time::{MgaGalTime, MgaGalTimeOwned, MgaGalTimeRef},
+    mga_glo_alm::{MgaGloAlm, MgaGloAlmOwned, MgaGloAlmRef},
     mga_glo_eph::{MgaGloEph, MgaGloEphOwned, MgaGloEphRef},
+    mga_gps_alm::{MgaGpsAlm, MgaGpsAlmOwned, MgaGpsAlmRef},
     mga_gps_eph::{MgaGpsEph, MgaGpsEphOwned, MgaGpsEphRef},
+    mga_gps_health::{MgaGpsHealth, MgaGpsHealthOwned, MgaGpsHealthRef},
     mga_gps_iono::{MgaGpsIono, MgaGpsIonoOwned, MgaGpsIonoRef},
     mga_gps_utc::{MgaGpsUtc, MgaGpsUtcOwned, MgaGpsUtcRef},
+    mga_qzss_alm::{MgaQzssAlm, MgaQzssAlmOwned, MgaQzssAlmRef},
+    mga_qzss_eph::{MgaQzssEph, MgaQzssEphOwned, MgaQzssEphRef},
+    mga_qzss_health::{MgaQzssHealth, MgaQzssHealthOwned, MgaQzssHealthRef},
     mon_comms::{MonComms, MonCommsOwned, MonCommsRef},
     mon_gnss::{MonGnss, MonGnssOwned, MonGnssRef},
     mon_hw::{MonHw, MonHwOwned, MonHwRef},
@@ -52,12 +64,14 @@ use crate::ubx_packets::packets::{
     mon_rxbuf::{MonRxbuf, MonRxbufOwned, MonRxbufRef},
     mon_rxr::{MonRxr, MonRxrOwned, MonRxrRef},
     mon_span::{MonSpan, MonSpanOwned, MonSpanRef},
+    mon_sys::{MonSys, MonSysOwned, MonSysRef},
     mon_txbuf::{MonTxbuf, MonTxbufOwned, MonTxbufRef},
     mon_ver::{MonVer, MonVerOwned, MonVerRef},
     nav_att::{NavAtt, NavAttOwned, NavAttRef},
     nav_clock::{NavClock, NavClockOwned, NavClockRef},
     nav_cov::{NavCov, NavCovOwned, NavCovRef},
     nav_dop::{NavDop, NavDopOwned, NavDopRef},
+    nav_geofence::{NavGeofence, NavGeofenceOwned, NavGeofenceRef},
     nav_hp_pos_ecef::{NavHpPosEcef, NavHpPosEcefOwned, NavHpPosEcefRef},
     nav_hp_pos_llh::{NavHpPosLlh, NavHpPosLlhOwned, NavHpPosLlhRef},
     nav_orb::{NavOrb, NavOrbOwned, NavOrbRef},
@@ -71,23 +85,42 @@ use crate::ubx_packets::packets::{
     nav_pvt::proto31::{NavPvt, NavPvtOwned, NavPvtRef},
     nav_rel_pos_ned::proto31::{NavRelPosNed, NavRelPosNedOwned, NavRelPosNedRef},
     nav_sat::{NavSat, NavSatOwned, NavSatRef},
+    nav_sbas::{NavSbas, NavSbasOwned, NavSbasRef},
     nav_sig::{NavSig, NavSigOwned, NavSigRef},
+    nav_slas::{NavSlas, NavSlasOwned, NavSlasRef},
     nav_sol::{NavSol, NavSolOwned, NavSolRef},
     nav_status::{NavStatus, NavStatusOwned, NavStatusRef},
+    nav_svin::{NavSvin, NavSvinOwned, NavSvinRef},
+    nav_time_bds::{NavTimeBds, NavTimeBdsOwned, NavTimeBdsRef},
+    nav_time_gal::{NavTimeGal, NavTimeGalOwned, NavTimeGalRef},
+    nav_time_glo::{NavTimeGlo, NavTimeGloOwned, NavTimeGloRef},
     nav_time_gps::{NavTimeGps, NavTimeGpsOwned, NavTimeGpsRef},
     nav_time_ls::{NavTimeLs, NavTimeLsOwned, NavTimeLsRef},
+    nav_time_navic::{NavTimeNavic, NavTimeNavicOwned, NavTimeNavicRef},
+    nav_time_qzss::{NavTimeQzss, NavTimeQzssOwned, NavTimeQzssRef},
     nav_time_utc::{NavTimeUTC, NavTimeUTCOwned, NavTimeUTCRef},
     nav_vel_ned::{NavVelNed, NavVelNedOwned, NavVelNedRef},
     rxm_cor::{RxmCor, RxmCorOwned, RxmCorRef},
+    rxm_measx::{RxmMeasx, RxmMeasxOwned, RxmMeasxRef},
+    rxm_pmp::{RxmPmp, RxmPmpOwned, RxmPmpRef},
+    rxm_qzssl6::{RxmQzssl6, RxmQzssl6Owned, RxmQzssl6Ref},
     rxm_rawx::{RxmRawx, RxmRawxOwned, RxmRawxRef},
+    rxm_rlm::{
+        RxmRlmLong, RxmRlmLongOwned, RxmRlmLongRef, RxmRlmShort, RxmRlmShortOwned, RxmRlmShortRef,
+    },
     rxm_rtcm::{RxmRtcm, RxmRtcmOwned, RxmRtcmRef},
     rxm_sfrbx::{RxmSfrbx, RxmSfrbxOwned, RxmSfrbxRef},
+    rxm_spartn::{RxmSpartn, RxmSpartnOwned, RxmSpartnRef},
+    rxm_spartnkey::{RxmSpartnKey, RxmSpartnKeyOwned, RxmSpartnKeyRef},
+    sec_ecsign::{SecEcsign, SecEcsignOwned, SecEcsignRef},
     sec_sig::{SecSig, SecSigOwned, SecSigRef},
     sec_siglog::{SecSiglog, SecSiglogOwned, SecSiglogRef},
     sec_uniq_id::{SecUniqId, SecUniqIdOwned, SecUniqIdRef},
+    tim_smeas::{TimSmeas, TimSmeasOwned, TimSmeasRef},
     tim_svin::{TimSvin, TimSvinOwned, TimSvinRef},
     tim_tm2::{TimTm2, TimTm2Owned, TimTm2Ref},
     tim_tp::{TimTp, TimTpOwned, TimTpRef},
+    tim_vrfy::{TimVrfy, TimVrfyOwned, TimVrfyRef},
 };
 use crate::{ParserError, UbxPacketMeta, UbxUnknownPacketOwned, UbxUnknownPacketRef};
 use ublox_derive::define_recv_packets;
@@ -137,6 +170,7 @@ define_recv_packets!(
         MonRxr,
         MonRxbuf,
         MonTxbuf,
+        MonSys,
         MgaAck,
         MgaGpsIono,
         MgaGpsEph,
@@ -146,6 +180,18 @@ define_recv_packets!(
         MgaGalEph,
         MgaGalTime,
         MgaGloEph,
+        MgaAno,
+        MgaDbd,
+        MgaFlashAck,
+        MgaGpsAlm,
+        MgaGpsHealth,
+        MgaGalAlm,
+        MgaBdsAlm,
+        MgaBdsHealth,
+        MgaGloAlm,
+        MgaQzssEph,
+        MgaQzssAlm,
+        MgaQzssHealth,
         NavAtt,
         NavClock,
         NavCov,
@@ -168,15 +214,34 @@ define_recv_packets!(
         NavTimeUTC,
         NavTimeLs,
         NavVelECEF,
+        NavSvin,
+        NavTimeBds,
+        NavTimeGal,
+        NavTimeGlo,
+        NavTimeQzss,
+        NavTimeNavic,
+        NavGeofence,
+        NavSbas,
+        NavSlas,
         RxmCor,
         RxmRawx,
         RxmRtcm,
         RxmSfrbx,
+        RxmMeasx,
+        RxmPmp,
+        RxmQzssl6,
+        RxmSpartn,
+        RxmSpartnKey,
+        RxmRlmShort,
+        RxmRlmLong,
         SecSig,
         SecSiglog,
         SecUniqId,
+        SecEcsign,
         TimSvin,
         TimTp,
         TimTm2,
+        TimVrfy,
+        TimSmeas,
     }
 );
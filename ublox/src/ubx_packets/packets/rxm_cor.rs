@@ -145,3 +145,13 @@ pub enum MsgDecrypted {
     NotDecrypted = 1,
     Decrypted = 2,
 }
+
+/// Decodes a 2-bit `msgUsed` field, as found in RXM-COR and RXM-SPARTN
+pub(crate) fn correction_msg_used(bits: u8) -> CorrectionMsgUsed {
+    CorrectionMsgUsed::from(bits & 0x03)
+}
+
+/// Decodes a 2-bit `errStatus` field, as found in RXM-COR and RXM-QZSSL6
+pub(crate) fn correction_err_status(bits: u8) -> CorrectionErrStatus {
+    CorrectionErrStatus::from(bits & 0x03)
+}
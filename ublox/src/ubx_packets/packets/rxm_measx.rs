@@ -0,0 +1,219 @@
+#[allow(unused_imports, reason = "It is only unused in some feature sets")]
+use crate::FieldIter;
+#[cfg(feature = "serde")]
+use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_packet_recv};
+
+/// Satellite measurements for RRLP
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x14, max_payload_len = 6164)] // 44 + 255 * 24
+struct RxmMeasx {
+    /// Message version (1 for this version)
+    version: u8,
+
+    reserved1: [u8; 3],
+
+    /// GPS measurement reference time (ms)
+    gps_tow: u32,
+
+    /// GLONASS measurement reference time (ms)
+    glo_tow: u32,
+
+    /// BeiDou measurement reference time (ms)
+    bds_tow: u32,
+
+    reserved2: [u8; 4],
+
+    /// QZSS measurement reference time (ms)
+    qzss_tow: u32,
+
+    /// GPS measurement reference time accuracy (ms)
+    #[ubx(map_type = f64, scale = 0.0625, alias = gps_tow_acc_ms)]
+    gps_tow_acc: u16,
+
+    /// GLONASS measurement reference time accuracy (ms)
+    #[ubx(map_type = f64, scale = 0.0625, alias = glo_tow_acc_ms)]
+    glo_tow_acc: u16,
+
+    /// BeiDou measurement reference time accuracy (ms)
+    #[ubx(map_type = f64, scale = 0.0625, alias = bds_tow_acc_ms)]
+    bds_tow_acc: u16,
+
+    reserved3: [u8; 2],
+
+    /// QZSS measurement reference time accuracy (ms)
+    #[ubx(map_type = f64, scale = 0.0625, alias = qzss_tow_acc_ms)]
+    qzss_tow_acc: u16,
+
+    /// Number of satellites following
+    num_sv: u8,
+
+    /// TOW set status, see [RxmMeasxTowSet]
+    #[ubx(map_type = RxmMeasxTowSet, from = RxmMeasxTowSet::from_flags)]
+    flags: u8,
+
+    reserved4: [u8; 8],
+
+    #[ubx(
+        map_type = RxmMeasxSvIter,
+        from = RxmMeasxSvIter::new,
+        may_fail,
+        is_valid = RxmMeasxSvIter::is_valid,
+    )]
+    svs: [u8; 0],
+}
+
+/// Whether the TOW fields of `RxmMeasx` are set
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RxmMeasxTowSet {
+    NotSet,
+    Set,
+}
+
+impl RxmMeasxTowSet {
+    fn from_flags(flags: u8) -> Self {
+        match flags & 0x3 {
+            0 => Self::NotSet,
+            _ => Self::Set,
+        }
+    }
+}
+
+/// Multipath index of a satellite measurement
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MultipathIndicator {
+    NotMeasured = 0,
+    Low = 1,
+    Medium = 2,
+    High = 3,
+}
+
+#[derive(Debug, Clone)]
+pub struct RxmMeasxSvIter<'a>(core::slice::ChunksExact<'a, u8>);
+
+impl<'a> RxmMeasxSvIter<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self(data.chunks_exact(24))
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len().is_multiple_of(24)
+    }
+}
+
+impl<'a> core::iter::Iterator for RxmMeasxSvIter<'a> {
+    type Item = RxmMeasxSvRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(RxmMeasxSvRef)
+    }
+}
+
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x14, fixed_payload_len = 24)]
+struct RxmMeasxSv {
+    /// GNSS identifier
+    gnss_id: u8,
+
+    /// Satellite identifier
+    sv_id: u8,
+
+    /// Carrier noise ratio (0..63)
+    c_no: u8,
+
+    /// Multipath index, see [MultipathIndicator]
+    #[ubx(map_type = MultipathIndicator)]
+    mpath_indic: u8,
+
+    /// Doppler measurement (m/s)
+    #[ubx(map_type = f64, scale = 0.04, alias = doppler_meters_per_sec)]
+    doppler_ms: i32,
+
+    /// Doppler measurement (Hz)
+    #[ubx(map_type = f64, scale = 0.2)]
+    doppler_hz: i32,
+
+    /// Whole value of the code phase measurement (0..1022 chips)
+    whole_chips: u16,
+
+    /// Fractional value of the code phase measurement (0..1023, in 1/1024 chip)
+    frac_chips: u16,
+
+    /// Code phase (ms)
+    #[ubx(map_type = f64, scale = 4.76837158203125e-7, alias = code_phase_ms)] // 2^-21
+    code_phase: u32,
+
+    /// Integer (part of) the code phase (ms)
+    int_code_phase: u8,
+
+    /// Pseudorange RMS error index
+    pseu_range_rms_err: u8,
+
+    reserved5: [u8; 2],
+}
+
+macro_rules! impl_chips {
+    ($type:ty) => {
+        impl $type {
+            /// Code phase measurement in chips, combining `whole_chips` and `frac_chips`
+            pub fn chips(&self) -> f64 {
+                f64::from(self.whole_chips()) + f64::from(self.frac_chips()) / 1024.0
+            }
+        }
+    };
+}
+
+impl_chips!(RxmMeasxSvRef<'_>);
+impl_chips!(RxmMeasxSvOwned);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sv_measurements() {
+        let mut payload = [0u8; 44 + 24];
+        payload[0] = 1; // version
+        payload[4..8].copy_from_slice(&345_600_000u32.to_le_bytes()); // gps_tow
+        payload[24..26].copy_from_slice(&40u16.to_le_bytes()); // gps_tow_acc: 2.5 ms
+        payload[34] = 1; // num_sv
+        payload[35] = 0x02; // flags
+        let sv = &mut payload[44..];
+        sv[0] = 0; // GPS
+        sv[1] = 12;
+        sv[2] = 42;
+        sv[3] = 2;
+        sv[4..8].copy_from_slice(&(-25i32).to_le_bytes()); // -1 m/s
+        sv[8..12].copy_from_slice(&(-26i32).to_le_bytes()); // -5.2 Hz
+        sv[12..14].copy_from_slice(&511u16.to_le_bytes());
+        sv[14..16].copy_from_slice(&256u16.to_le_bytes());
+        sv[16..20].copy_from_slice(&(1u32 << 20).to_le_bytes()); // 0.5 ms
+        sv[20] = 3;
+        sv[21] = 17;
+        assert!(RxmMeasxRef::validate(&payload).is_ok());
+        assert!(RxmMeasxRef::validate(&payload[..50]).is_err());
+
+        let packet = RxmMeasxRef(&payload);
+        assert_eq!(packet.gps_tow(), 345_600_000);
+        assert!((packet.gps_tow_acc_ms() - 2.5).abs() < 1e-9);
+        assert_eq!(packet.flags(), RxmMeasxTowSet::Set);
+
+        let mut svs = packet.svs();
+        let sv = svs.next().unwrap();
+        assert_eq!((sv.gnss_id(), sv.sv_id(), sv.c_no()), (0, 12, 42));
+        assert_eq!(sv.mpath_indic(), MultipathIndicator::Medium);
+        assert!((sv.doppler_meters_per_sec() + 1.0).abs() < 1e-9);
+        assert!((sv.doppler_hz() + 5.2).abs() < 1e-9);
+        assert!((sv.chips() - 511.25).abs() < 1e-9);
+        assert!((sv.code_phase_ms() - 0.5).abs() < 1e-12);
+        assert_eq!(sv.int_code_phase(), 3);
+        assert_eq!(sv.pseu_range_rms_err(), 17);
+        assert!(svs.next().is_none());
+    }
+}
@@ -0,0 +1,96 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[allow(unused_imports, reason = "It's only unused in some feature sets")]
+use crate::FieldIter;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv;
+
+/// PMP (L-band) user data frame
+///
+/// Output by L-band correction receivers for each successfully decoded frame.
+/// The user data can be forwarded as-is to a high precision receiver.
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x72, max_payload_len = 528)] // 24 + 504
+struct RxmPmp {
+    /// Message version (0x00 with a fixed 504 byte user data block, 0x01 with a variable one)
+    version: u8,
+
+    reserved0: u8,
+
+    /// Number of valid bytes in the user data block
+    num_bytes_user_data: u16,
+
+    /// Time since startup when the frame started (ms)
+    time_tag: u32,
+
+    /// Received unique words, first word
+    unique_word1: u32,
+
+    /// Received unique words, second word
+    unique_word2: u32,
+
+    /// Received service identifier
+    service_identifier: u16,
+
+    /// Received spare data
+    spare: u8,
+
+    /// Number of bit errors in both unique words
+    unique_word_bit_errors: u8,
+
+    /// Number of bits corrected by FEC (forward error correction)
+    fec_bits: u16,
+
+    /// Energy per bit to noise power spectral density ratio (dB)
+    #[ubx(map_type = f32, scale = 0.125)]
+    ebno: u8,
+
+    reserved1: u8,
+
+    /// User data block as received, see [RxmPmpRef::user_data] for the valid part
+    #[ubx(map_type = &[u8], from = user_data_from_bytes, get_as_ref)]
+    user_data_block: [u8; 0],
+}
+
+fn user_data_from_bytes(bytes: &[u8]) -> &[u8] {
+    bytes
+}
+
+impl RxmPmpRef<'_> {
+    /// The valid part of the user data block, ready to be forwarded
+    pub fn user_data(&self) -> &[u8] {
+        let block = self.user_data_block();
+        let len = usize::from(self.num_bytes_user_data()).min(block.len());
+        &block[..len]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_data_is_truncated_to_valid_bytes() {
+        let mut payload = [0u8; 24 + 504];
+        payload[2..4].copy_from_slice(&3u16.to_le_bytes()); // num_bytes_user_data
+        payload[4..8].copy_from_slice(&1_000u32.to_le_bytes()); // time_tag
+        payload[16..18].copy_from_slice(&0x1234u16.to_le_bytes()); // service_identifier
+        payload[22] = 80; // ebno: 10 dB
+        payload[24..27].copy_from_slice(&[0xaa, 0xbb, 0xcc]);
+        assert!(RxmPmpRef::validate(&payload).is_ok());
+
+        let packet = RxmPmpRef(&payload);
+        assert_eq!(packet.time_tag(), 1_000);
+        assert_eq!(packet.service_identifier(), 0x1234);
+        assert!((packet.ebno() - 10.0).abs() < 1e-6);
+        assert_eq!(packet.user_data_block().len(), 504);
+        assert_eq!(packet.user_data(), &[0xaa, 0xbb, 0xcc]);
+
+        // Version 1 frames only carry the valid bytes
+        let packet = RxmPmpRef(&payload[..27]);
+        assert_eq!(packet.user_data(), &[0xaa, 0xbb, 0xcc]);
+    }
+}
@@ -1,9 +1,15 @@
 use bitflags::bitflags;
 
-use crate::{ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta};
+use crate::{
+    error::PowerManagementError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator,
+    UbxPacketMeta,
+};
 
 use ublox_derive::{ubx_extend_bitflags, ubx_packet_send};
 
+/// Longest backup duration supported by the receiver (12 days, in ms)
+const MAX_DURATION_MS: u32 = 12 * 24 * 3600 * 1000;
+
 /// Request a power management related task of the receiver
 #[ubx_packet_send]
 #[ubx(class = 0x02, id = 0x41, fixed_payload_len = 8)]
@@ -17,11 +23,160 @@ pub struct RxmPmreq {
     flags: u32,
 }
 
+impl RxmPmreqBuilder {
+    /// Checks the request against the combinations accepted by the receiver
+    pub fn validate(&self) -> Result<(), PowerManagementError> {
+        validate_request(self.duration_ms, self.flags, None)
+    }
+
+    /// Same as `into_packet_bytes`, but rejects requests the receiver would ignore
+    pub fn try_into_packet_bytes(self) -> Result<[u8; Self::PACKET_LEN], PowerManagementError> {
+        self.validate()?;
+        Ok(self.into_packet_bytes())
+    }
+}
+
+/// Request a power management related task of the receiver,
+/// with the sources that may wake it up (version 0x00, 16 bytes)
+#[ubx_packet_send]
+#[ubx(class = 0x02, id = 0x41, fixed_payload_len = 16)]
+pub struct RxmPmreqWakeup {
+    /// Message version (0x00 for this version)
+    version: u8,
+    reserved1: [u8; 3],
+
+    /// Duration of the requested task in ms, set zero to infinite
+    /// duration
+    duration_ms: u32,
+
+    /// Task flags. See [RxmPmreqFlags]
+    #[ubx(map_type = RxmPmreqFlags)]
+    flags: u32,
+
+    /// Sources that wake the receiver up, see [RxmPmreqWakeupSources]
+    #[ubx(map_type = RxmPmreqWakeupSources)]
+    wakeup_sources: u32,
+}
+
+impl RxmPmreqWakeupBuilder {
+    /// Backup request for `duration_ms` (0 for infinite), ended early by any of `wakeup_sources`
+    pub fn backup(duration_ms: u32, wakeup_sources: RxmPmreqWakeupSources) -> Self {
+        Self {
+            version: 0,
+            reserved1: [0; 3],
+            duration_ms,
+            flags: RxmPmreqFlags::BACKUP,
+            wakeup_sources,
+        }
+    }
+
+    /// Checks the request against the combinations accepted by the receiver
+    pub fn validate(&self) -> Result<(), PowerManagementError> {
+        validate_request(self.duration_ms, self.flags, Some(self.wakeup_sources))
+    }
+
+    /// Same as `into_packet_bytes`, but rejects requests the receiver would ignore
+    pub fn try_into_packet_bytes(self) -> Result<[u8; Self::PACKET_LEN], PowerManagementError> {
+        self.validate()?;
+        Ok(self.into_packet_bytes())
+    }
+}
+
+fn validate_request(
+    duration_ms: u32,
+    flags: RxmPmreqFlags,
+    wakeup_sources: Option<RxmPmreqWakeupSources>,
+) -> Result<(), PowerManagementError> {
+    if !flags.contains(RxmPmreqFlags::BACKUP) {
+        return Err(PowerManagementError::BackupFlagMissing);
+    }
+    if duration_ms > MAX_DURATION_MS {
+        return Err(PowerManagementError::DurationTooLong);
+    }
+    if duration_ms == 0 && wakeup_sources.is_some_and(|s| s.is_empty()) {
+        return Err(PowerManagementError::NoWakeupSource);
+    }
+    Ok(())
+}
+
 #[ubx_extend_bitflags]
 #[ubx(from, into_raw, rest_reserved)]
 bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct RxmPmreqFlags: u32 {
         /// The receiver goes into backup mode for a time period
         const BACKUP = 0x02;
+        /// Force receiver backup while USB is connected, the USB
+        /// interface will be disabled
+        const FORCE = 0x04;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// Sources that wake the receiver up from backup mode
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct RxmPmreqWakeupSources: u32 {
+        /// Wake up on a rising or falling edge on the UART RX pin
+        const UART_RX = 0x08;
+        /// Wake up on a rising or falling edge on the EXTINT0 pin
+        const EXTINT0 = 0x20;
+        /// Wake up on a rising or falling edge on the EXTINT1 pin
+        const EXTINT1 = 0x40;
+        /// Wake up on a rising or falling edge on the SPI CS pin
+        const SPI_CS = 0x80;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backup_with_wakeup_sources() {
+        let bytes = RxmPmreqWakeupBuilder::backup(60_000, RxmPmreqWakeupSources::EXTINT0)
+            .try_into_packet_bytes()
+            .unwrap();
+        assert_eq!(
+            &bytes[..6],
+            &[0xb5, 0x62, 0x02, 0x41, 0x10, 0x00] // header, 16 bytes long
+        );
+        assert_eq!(
+            &bytes[6..22],
+            &[
+                0x00, 0x00, 0x00, 0x00, // version, reserved1
+                0x60, 0xea, 0x00, 0x00, // duration
+                0x02, 0x00, 0x00, 0x00, // flags
+                0x20, 0x00, 0x00, 0x00, // wakeup_sources
+            ]
+        );
+    }
+
+    #[test]
+    fn rejected_requests() {
+        assert_eq!(
+            RxmPmreqWakeupBuilder::backup(0, RxmPmreqWakeupSources::empty()).validate(),
+            Err(PowerManagementError::NoWakeupSource)
+        );
+        assert_eq!(
+            RxmPmreqWakeupBuilder::backup(MAX_DURATION_MS + 1, RxmPmreqWakeupSources::UART_RX)
+                .validate(),
+            Err(PowerManagementError::DurationTooLong)
+        );
+        let request = RxmPmreqBuilder {
+            duration_ms: 1000,
+            flags: RxmPmreqFlags::FORCE,
+        };
+        assert_eq!(
+            request.validate(),
+            Err(PowerManagementError::BackupFlagMissing)
+        );
+        // The 8-byte request has no wake-up sources, infinite duration is accepted
+        let request = RxmPmreqBuilder {
+            duration_ms: 0,
+            flags: RxmPmreqFlags::BACKUP,
+        };
+        assert!(request.validate().is_ok());
     }
 }
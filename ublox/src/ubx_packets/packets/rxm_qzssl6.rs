@@ -0,0 +1,111 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[allow(unused_imports, reason = "It's only unused in some feature sets")]
+use crate::FieldIter;
+
+use core::fmt;
+
+use super::rxm_cor::{correction_err_status, CorrectionErrStatus};
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_packet_recv};
+
+/// QZSS L6 message
+///
+/// Output by QZSS L6 correction receivers for each decoded L6 message.
+/// The message bytes can be forwarded as-is to a high precision receiver.
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x73, max_payload_len = 264)] // 14 + 250
+struct RxmQzssl6 {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Satellite identifier
+    sv_id: u8,
+
+    /// Mean C/N0 (dB-Hz)
+    #[ubx(map_type = f32, scale = 0.00390625)] // 2^-8
+    cno: u16,
+
+    /// Time since startup when the L6 message was received (ms)
+    time_tag: u32,
+
+    /// L6 group delay w.r.t. L2 on channel
+    group_delay: u8,
+
+    /// Number of bit errors corrected by Reed-Solomon decoder
+    bit_err_corr: u8,
+
+    /// Channel information, see [RxmQzssl6ChInfo]
+    #[ubx(map_type = RxmQzssl6ChInfo)]
+    ch_info: u16,
+
+    reserved0: [u8; 2],
+
+    /// L6 message bytes (250 bytes)
+    #[ubx(
+        map_type = &[u8],
+        from = msg_bytes_from_bytes,
+        is_valid = msg_bytes_is_valid,
+        get_as_ref,
+    )]
+    msg_bytes: [u8; 0],
+}
+
+fn msg_bytes_from_bytes(bytes: &[u8]) -> &[u8] {
+    bytes
+}
+
+#[allow(dead_code, reason = "Used by ubx_packet_recv macro for validation")]
+fn msg_bytes_is_valid(bytes: &[u8]) -> bool {
+    bytes.len() == 250
+}
+
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RxmQzssl6ChInfo(u16);
+
+impl RxmQzssl6ChInfo {
+    /// L6 channel (0 = channel A, 1 = channel B)
+    pub fn chn(self) -> u8 {
+        ((self.0 >> 8) & 0x3) as u8
+    }
+
+    /// Name of the L6 message
+    pub fn msg_name(self) -> L6MsgName {
+        L6MsgName::from(((self.0 >> 10) & 0x1) as u8)
+    }
+
+    /// Reed-Solomon decoding status of the L6 message
+    pub fn err_status(self) -> CorrectionErrStatus {
+        correction_err_status((self.0 >> 12) as u8)
+    }
+
+    const fn from(x: u16) -> Self {
+        Self(x)
+    }
+}
+
+impl fmt::Debug for RxmQzssl6ChInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RxmQzssl6ChInfo")
+            .field("chn", &self.chn())
+            .field("msg_name", &self.msg_name())
+            .field("err_status", &self.err_status())
+            .finish()
+    }
+}
+
+/// Name of a QZSS L6 message
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum L6MsgName {
+    /// L6D (CLAS)
+    L6D = 0,
+    /// L6E (MADOCA-PPP)
+    L6E = 1,
+}
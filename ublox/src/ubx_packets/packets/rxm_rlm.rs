@@ -0,0 +1,150 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use core::fmt;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv;
+
+/// Galileo SAR short-RLM report
+///
+/// Shares class and ID with [RxmRlmLong], the two are told apart by their payload length.
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x59, fixed_payload_len = 16)]
+struct RxmRlmShort {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Message type (0x01 for Short-RLM)
+    msg_type: u8,
+
+    /// Identifier of the satellite which broadcast the RLM
+    sv_id: u8,
+
+    reserved0: u8,
+
+    /// Beacon identifier, see [SarBeaconId]
+    #[ubx(map_type = SarBeaconId, from = SarBeaconId::from_bytes, get_as_ref)]
+    beacon: [u8; 8],
+
+    /// Message code (4 bits)
+    message: u8,
+
+    /// Parameters (16 bits), with bytes ordered by earliest transmitted first
+    params: [u8; 2],
+
+    reserved1: u8,
+}
+
+/// Galileo SAR long-RLM report
+///
+/// Shares class and ID with [RxmRlmShort], the two are told apart by their payload length.
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x59, fixed_payload_len = 28)]
+struct RxmRlmLong {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Message type (0x02 for Long-RLM)
+    msg_type: u8,
+
+    /// Identifier of the satellite which broadcast the RLM
+    sv_id: u8,
+
+    reserved0: u8,
+
+    /// Beacon identifier, see [SarBeaconId]
+    #[ubx(map_type = SarBeaconId, from = SarBeaconId::from_bytes, get_as_ref)]
+    beacon: [u8; 8],
+
+    /// Message code (4 bits)
+    message: u8,
+
+    /// Parameters (96 bits), with bytes ordered by earliest transmitted first
+    params: [u8; 12],
+
+    reserved1: [u8; 3],
+}
+
+/// 60-bit identifier of a Cospas-Sarsat distress beacon
+///
+/// Conventionally written as 15 hexadecimal digits, which is what the [fmt::Display]
+/// implementation produces.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SarBeaconId(u64);
+
+impl SarBeaconId {
+    const MASK: u64 = (1 << 60) - 1;
+
+    /// The beacon bytes are ordered by earliest transmitted (most significant) first,
+    /// the top four bits of the first byte are zero
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let bytes = [
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ];
+        Self(u64::from_be_bytes(bytes) & Self::MASK)
+    }
+
+    /// Numeric value of the beacon identifier
+    pub fn value(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for SarBeaconId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:015X}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_and_long_rlm() {
+        let beacon = [0x09, 0xc6, 0x00, 0x0a, 0xbc, 0xde, 0xf0, 0x12];
+        let mut short = [0u8; 16];
+        short[1] = 0x01;
+        short[2] = 12;
+        short[4..12].copy_from_slice(&beacon);
+        short[12] = 0x01;
+        short[13..15].copy_from_slice(&[0x80, 0x01]);
+
+        let mut long = [0u8; 28];
+        long[1] = 0x02;
+        long[2] = 24;
+        long[4..12].copy_from_slice(&beacon);
+        long[12] = 0x0f;
+        long[13] = 0xff;
+
+        assert!(RxmRlmShortRef::validate(&short).is_ok());
+        assert!(RxmRlmLongRef::validate(&short).is_err());
+        assert!(RxmRlmLongRef::validate(&long).is_ok());
+        assert!(RxmRlmShortRef::validate(&long).is_err());
+
+        let packet = RxmRlmShortRef(&short);
+        assert_eq!(packet.sv_id(), 12);
+        assert_eq!(packet.beacon().value(), 0x09c6_000a_bcde_f012);
+        assert_eq!(packet.message(), 1);
+        assert_eq!(packet.params(), [0x80, 0x01]);
+
+        let packet = RxmRlmLongRef(&long);
+        assert_eq!(packet.sv_id(), 24);
+        assert_eq!(packet.message(), 0x0f);
+        assert_eq!(packet.params()[0], 0xff);
+        assert_eq!(packet.beacon(), RxmRlmShortRef(&short).beacon());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn beacon_id_display() {
+        use alloc::string::ToString;
+
+        let id = SarBeaconId::from_bytes(&[0xf9, 0xc6, 0x00, 0x0a, 0xbc, 0xde, 0xf0, 0x12]);
+        assert_eq!(id.to_string(), "9C6000ABCDEF012");
+    }
+}
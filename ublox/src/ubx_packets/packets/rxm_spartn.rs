@@ -0,0 +1,34 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use super::rxm_cor::{correction_msg_used, CorrectionMsgUsed};
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv;
+
+/// SPARTN input status
+///
+/// Output for each SPARTN message fed to the receiver.
+#[ubx_packet_recv]
+#[ubx(class = 0x02, id = 0x33, fixed_payload_len = 8)]
+struct RxmSpartn {
+    /// Message version (0x01 for this version)
+    version: u8,
+
+    /// Whether the receiver used the SPARTN message, see [CorrectionMsgUsed]
+    #[ubx(map_type = CorrectionMsgUsed, from = msg_used_from_flags)]
+    flags: u8,
+
+    /// SPARTN message sub type
+    sub_type: u16,
+
+    reserved1: [u8; 2],
+
+    /// SPARTN message type
+    msg_type: u16,
+}
+
+fn msg_used_from_flags(flags: u8) -> CorrectionMsgUsed {
+    correction_msg_used(flags >> 1)
+}
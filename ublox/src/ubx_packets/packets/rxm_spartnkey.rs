@@ -0,0 +1,331 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[allow(unused_imports, reason = "It's only unused in some feature sets")]
+use crate::FieldIter;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::fmt;
+
+use crate::{error::ParserError, ubx_checksum, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv_send;
+
+/// Dynamic SPARTN keys
+///
+/// Transfers the current and next dynamic keys used to decrypt SPARTN messages.
+/// The receiver outputs it when polled with
+/// `UbxPacketRequest::request_for::<RxmSpartnKey>()`, and accepts it as input to set the keys.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// # use ublox::rxm_spartnkey::{RxmSpartnKeyBuilder, SpartnKeyInfo};
+/// let current = [0x11u8; 16];
+/// let next = [0x22u8; 16];
+/// let infos = [
+///     SpartnKeyInfo { key_length_bytes: 16, valid_from_wno: 2300, valid_from_tow: 0 },
+///     SpartnKeyInfo { key_length_bytes: 16, valid_from_wno: 2304, valid_from_tow: 0 },
+/// ];
+/// let keys = [current, next].concat();
+///
+/// let mut buffer = Vec::new();
+/// RxmSpartnKeyBuilder::default()
+///     .with_keys(&infos, &keys)
+///     .expect("key lengths match the key data")
+///     .extend_to(&mut buffer);
+/// ```
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x02,
+    id = 0x36,
+    max_payload_len = 1024,
+    flags = "default_for_builder"
+)]
+struct RxmSpartnKey<'a> {
+    /// Message version (0x01 for this version)
+    version: u8,
+
+    /// Number of keys
+    num_keys: u8,
+
+    reserved0: u16,
+
+    #[ubx(
+        map_type = SpartnKeyInfoIter<'a>,
+        from = SpartnKeyInfoIter::new,
+        size_fn = key_infos_len,
+    )]
+    key_infos: [u8; 0],
+
+    /// Key payloads of all keys, concatenated in the order of `key_infos`
+    #[ubx(
+        map_type = &'a [u8],
+        from = RxmSpartnKey::key_data_from_bytes,
+        size_fn = key_data_len,
+        into = RxmSpartnKey::key_data_into_bytes,
+    )]
+    key_data: [u8; 0],
+}
+
+impl RxmSpartnKey {
+    fn key_data_from_bytes(bytes: &[u8]) -> &[u8] {
+        bytes
+    }
+
+    fn key_data_into_bytes(bytes: &[u8]) -> &[u8] {
+        bytes
+    }
+}
+
+impl RxmSpartnKeyRef<'_> {
+    fn key_infos_len(&self) -> usize {
+        usize::from(self.num_keys()) * SpartnKeyInfo::BLOCK_SIZE
+    }
+
+    fn key_data_len(&self) -> usize {
+        key_data_len(self.0, self.key_infos_len())
+    }
+
+    /// Iterates over the keys, pairing each key info with its key payload
+    pub fn keys(&self) -> SpartnKeyIter<'_> {
+        SpartnKeyIter {
+            infos: self.key_infos(),
+            data: self.key_data(),
+        }
+    }
+}
+
+impl RxmSpartnKeyOwned {
+    fn key_infos_len(&self) -> usize {
+        usize::from(self.num_keys()) * SpartnKeyInfo::BLOCK_SIZE
+    }
+
+    fn key_data_len(&self) -> usize {
+        key_data_len(&self.0[..], self.key_infos_len())
+    }
+}
+
+/// Sum of the key lengths of the key infos following the 4 byte header, or
+/// 0 if the payload is too short to hold them, so that validation reports
+/// the missing key infos instead of reading past the payload
+fn key_data_len(payload: &[u8], key_infos_len: usize) -> usize {
+    payload
+        .get(4..4 + key_infos_len)
+        .map(|infos| {
+            SpartnKeyInfoIter::new(infos)
+                .map(|info| usize::from(info.key_length_bytes))
+                .sum()
+        })
+        .unwrap_or_default()
+}
+
+/// Validity and length of a single SPARTN key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpartnKeyInfo {
+    /// Number of bytes in the key payload
+    pub key_length_bytes: u8,
+    /// GPS week number from which the key is valid
+    pub valid_from_wno: u16,
+    /// GPS time of week from which the key is valid (s)
+    pub valid_from_tow: u32,
+}
+
+impl SpartnKeyInfo {
+    const BLOCK_SIZE: usize = 8;
+
+    fn from_bytes(chunk: &[u8]) -> Self {
+        Self {
+            key_length_bytes: chunk[1],
+            valid_from_wno: u16::from_le_bytes([chunk[2], chunk[3]]),
+            valid_from_tow: u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]),
+        }
+    }
+
+    pub fn extend_to<T>(&self, buf: &mut T) -> usize
+    where
+        T: core::iter::Extend<u8>,
+    {
+        buf.extend([0, self.key_length_bytes]);
+        buf.extend(self.valid_from_wno.to_le_bytes());
+        buf.extend(self.valid_from_tow.to_le_bytes());
+        Self::BLOCK_SIZE
+    }
+}
+
+#[derive(Clone)]
+pub enum SpartnKeyInfoIter<'a> {
+    // If the packet is of type `recv` we use this variant to iterate over the bytes
+    Bytes(core::slice::ChunksExact<'a, u8>),
+    // If the packet is of type `send` we use this variant to iterate over the slices
+    // of SpartnKeyInfo for RxmSpartnKeyBuilder and build the entire packet
+    Slice(core::slice::Iter<'a, SpartnKeyInfo>),
+}
+
+impl<'a> SpartnKeyInfoIter<'a> {
+    fn from_slice(infos: &'a [SpartnKeyInfo]) -> Self {
+        Self::Slice(infos.iter())
+    }
+
+    // For internal use by recv variant
+    fn new(bytes: &'a [u8]) -> Self {
+        Self::Bytes(bytes.chunks_exact(SpartnKeyInfo::BLOCK_SIZE))
+    }
+}
+
+impl Default for SpartnKeyInfoIter<'_> {
+    fn default() -> Self {
+        Self::from_slice(&[])
+    }
+}
+
+impl fmt::Debug for SpartnKeyInfoIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl core::iter::Iterator for SpartnKeyInfoIter<'_> {
+    type Item = SpartnKeyInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Bytes(chunks) => chunks.next().map(SpartnKeyInfo::from_bytes),
+            Self::Slice(it) => it.next().copied(),
+        }
+    }
+}
+
+/// Iterator over the keys of a [RxmSpartnKeyRef], yielding each key info with its payload
+#[derive(Debug, Clone)]
+pub struct SpartnKeyIter<'a> {
+    infos: SpartnKeyInfoIter<'a>,
+    data: &'a [u8],
+}
+
+impl<'a> core::iter::Iterator for SpartnKeyIter<'a> {
+    type Item = (SpartnKeyInfo, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let info = self.infos.next()?;
+        let (key, rest) = self
+            .data
+            .split_at_checked(usize::from(info.key_length_bytes))?;
+        self.data = rest;
+        Some((info, key))
+    }
+}
+
+/// Convenience method to set the keys for the builder
+impl<'a> RxmSpartnKeyBuilder<'a> {
+    /// Sets the keys to transfer. `key_data` holds the key payloads concatenated in
+    /// the order of `infos`, with lengths matching [SpartnKeyInfo::key_length_bytes].
+    ///
+    /// Returns `None` if there are more than 255 keys, if the length of `key_data`
+    /// is not the sum of the key lengths, or if the keys do not fit in a message.
+    pub fn with_keys(mut self, infos: &'a [SpartnKeyInfo], key_data: &'a [u8]) -> Option<Self> {
+        let num_keys = u8::try_from(infos.len()).ok()?;
+        let key_data_len: usize = infos
+            .iter()
+            .map(|info| usize::from(info.key_length_bytes))
+            .sum();
+        let payload_len = 4 + infos.len() * SpartnKeyInfo::BLOCK_SIZE + key_data.len();
+        if key_data.len() != key_data_len
+            || payload_len > usize::from(RxmSpartnKey::MAX_PAYLOAD_LEN)
+        {
+            return None;
+        }
+        self.version = 1;
+        self.num_keys = num_keys;
+        self.key_infos = SpartnKeyInfoIter::from_slice(infos);
+        self.key_data = key_data;
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports, reason = "unused in some feature sets")]
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn serialize_and_parse() {
+        let infos = [
+            SpartnKeyInfo {
+                key_length_bytes: 16,
+                valid_from_wno: 2300,
+                valid_from_tow: 86_400,
+            },
+            SpartnKeyInfo {
+                key_length_bytes: 4,
+                valid_from_wno: 2304,
+                valid_from_tow: 0,
+            },
+        ];
+        let mut key_data = [0x11u8; 20];
+        key_data[16..].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+
+        let mut out = Vec::new();
+        RxmSpartnKeyBuilder::default()
+            .with_keys(&infos, &key_data)
+            .unwrap()
+            .extend_to(&mut out);
+
+        const HEADER_LEN: usize = 6;
+        let len = u16::from_le_bytes([out[4], out[5]]) as usize;
+        assert_eq!(len, 4 + 2 * 8 + 20);
+        let payload = &out[HEADER_LEN..HEADER_LEN + len];
+        assert!(RxmSpartnKeyRef::validate(payload).is_ok());
+
+        let r = RxmSpartnKeyRef(payload);
+        assert_eq!(r.version(), 1);
+        assert_eq!(r.num_keys(), 2);
+        assert!(r.key_infos().eq(infos.iter().copied()));
+
+        let keys: Vec<_> = r.keys().collect();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0], (infos[0], &[0x11u8; 16][..]));
+        assert_eq!(keys[1], (infos[1], &[0xde, 0xad, 0xbe, 0xef][..]));
+    }
+
+    #[test]
+    fn reject_short_payload() {
+        // three key infos declared, none present
+        let payload = [0x01, 0x03, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0];
+        assert!(RxmSpartnKeyRef::validate(&payload).is_err());
+
+        // key info present, key payload truncated
+        let payload = [0x01, 0x01, 0, 0, 0, 0x10, 0, 0, 0, 0, 0, 0, 0x11, 0x11];
+        assert!(RxmSpartnKeyRef::validate(&payload).is_err());
+
+        let keys = SpartnKeyIter {
+            infos: SpartnKeyInfoIter::new(&payload[4..12]),
+            data: &payload[12..],
+        };
+        assert_eq!(keys.count(), 0);
+    }
+
+    #[test]
+    fn reject_invalid_keys() {
+        let info = SpartnKeyInfo {
+            key_length_bytes: 16,
+            valid_from_wno: 2300,
+            valid_from_tow: 0,
+        };
+        assert!(RxmSpartnKeyBuilder::default()
+            .with_keys(&[info], &[0; 16])
+            .is_some());
+        assert!(RxmSpartnKeyBuilder::default()
+            .with_keys(&[info], &[0; 15])
+            .is_none());
+        assert!(RxmSpartnKeyBuilder::default()
+            .with_keys(&[info; 256], &[0; 4096])
+            .is_none());
+        assert!(RxmSpartnKeyBuilder::default()
+            .with_keys(&[info; 60], &[0; 960])
+            .is_none());
+    }
+}
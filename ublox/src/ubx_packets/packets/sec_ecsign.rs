@@ -0,0 +1,221 @@
+//! SEC-ECSIGN: ECDSA signature of the message stream
+//!
+//! Hash of the messages output since the previous signature, signed with the
+//! receiver's private key for the current session.
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[cfg(feature = "sec-verify")]
+use p192::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+#[cfg(feature = "sec-verify")]
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "sec-verify")]
+use super::sec_sign::hash_frame;
+use crate::{error::ParserError, UbxPacketMeta};
+#[cfg(feature = "sec-verify")]
+use crate::{error::SecVerifyError, UbxPacket};
+use ublox_derive::ubx_packet_recv;
+
+/// ECDSA signature of the message stream
+#[ubx_packet_recv]
+#[ubx(class = 0x27, id = 0x04, fixed_payload_len = 108)]
+struct SecEcsign {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    reserved0: u8,
+
+    /// Number of messages hashed since the previous signature
+    msg_num: u16,
+
+    /// SHA-256 hash of the messages hashed since the previous signature
+    final_hash: [u8; 32],
+
+    /// Session ID, set when the signing key was generated
+    session_id: [u8; 24],
+
+    /// ECDSA signature component r
+    signature_r: [u8; 24],
+
+    /// ECDSA signature component s
+    signature_s: [u8; 24],
+}
+
+/// Verifies `SecEcsign` messages against the stream of messages preceding them
+///
+/// Every packet returned by the parser must be passed to [SecEcsignVerifier::update_packet]
+/// (or [SecEcsignVerifier::update]) in order, except the `SecEcsign` messages themselves,
+/// which are passed to [SecEcsignVerifier::verify].
+#[cfg(feature = "sec-verify")]
+#[derive(Clone)]
+pub struct SecEcsignVerifier {
+    key: VerifyingKey,
+    session_id: [u8; 24],
+    hasher: Sha256,
+    msg_num: u16,
+}
+
+#[cfg(feature = "sec-verify")]
+impl SecEcsignVerifier {
+    /// Verifier for the receiver's public `key` and the expected `session_id`
+    pub fn new(key: VerifyingKey, session_id: [u8; 24]) -> Self {
+        Self {
+            key,
+            session_id,
+            hasher: Sha256::new(),
+            msg_num: 0,
+        }
+    }
+
+    /// Verifier for a SEC1 encoded public key, as read from the receiver
+    pub fn from_sec1_bytes(key: &[u8], session_id: [u8; 24]) -> Option<Self> {
+        VerifyingKey::from_sec1_bytes(key)
+            .ok()
+            .map(|key| Self::new(key, session_id))
+    }
+
+    /// Adds a message, given by its class, ID and payload, to the signed stream
+    pub fn update(&mut self, class_id: u8, msg_id: u8, payload: &[u8]) {
+        hash_frame(&mut self.hasher, class_id, msg_id, payload);
+        self.msg_num = self.msg_num.wrapping_add(1);
+    }
+
+    /// Adds a packet returned by the parser to the signed stream
+    pub fn update_packet(&mut self, packet: &UbxPacket<'_>) {
+        let (class_id, msg_id) = packet.class_and_msg_id();
+        self.update(class_id, msg_id, packet.payload());
+    }
+
+    /// Checks `sign` against the messages added since the previous signature,
+    /// and starts a new stream
+    pub fn verify(&mut self, sign: &SecEcsignRef<'_>) -> Result<(), SecVerifyError> {
+        let hasher = core::mem::take(&mut self.hasher);
+        let msg_num = core::mem::take(&mut self.msg_num);
+
+        if sign.msg_num() != msg_num {
+            return Err(SecVerifyError::MessageCount {
+                expected: sign.msg_num(),
+                got: msg_num,
+            });
+        }
+        if hasher.finalize().as_slice() != sign.final_hash() {
+            return Err(SecVerifyError::HashMismatch);
+        }
+        if sign.session_id() != self.session_id {
+            return Err(SecVerifyError::SessionIdMismatch);
+        }
+
+        let signature = Signature::from_scalars(sign.signature_r(), sign.signature_s())
+            .map_err(|_| SecVerifyError::InvalidSignature)?;
+        let digest = Sha256::new()
+            .chain_update(sign.final_hash())
+            .chain_update(sign.session_id())
+            .finalize();
+        self.key
+            .verify_prehash(&digest, &signature)
+            .map_err(|_| SecVerifyError::InvalidSignature)
+    }
+}
+
+#[cfg(all(test, feature = "sec-verify"))]
+mod tests {
+    use super::*;
+    use p192::elliptic_curve::{bigint::U192, ops::Reduce, point::AffineCoordinates, PrimeField};
+    use p192::{FieldBytes, ProjectivePoint, Scalar};
+
+    const SESSION_ID: [u8; 24] = [0x5a; 24];
+
+    fn scalar(value: u64) -> Scalar {
+        Scalar::from(value)
+    }
+
+    /// Signs `digest` with the textbook ECDSA equations, p192 only implements verification
+    fn sign(d: Scalar, k: Scalar, digest: &[u8]) -> ([u8; 24], [u8; 24]) {
+        let z = <Scalar as Reduce<U192>>::reduce_bytes(FieldBytes::from_slice(&digest[..24]));
+        let r_point = (ProjectivePoint::GENERATOR * k).to_affine();
+        let r = <Scalar as Reduce<U192>>::reduce_bytes(&r_point.x());
+        let s = k.invert().unwrap() * (z + r * d);
+        (r.to_repr().into(), s.to_repr().into())
+    }
+
+    fn sign_payload(messages: &[(u8, u8, &[u8])], d: Scalar, session_id: [u8; 24]) -> [u8; 108] {
+        let mut hasher = Sha256::new();
+        for (class_id, msg_id, payload) in messages {
+            hash_frame(&mut hasher, *class_id, *msg_id, payload);
+        }
+        let final_hash = hasher.finalize();
+        let digest = Sha256::new()
+            .chain_update(final_hash)
+            .chain_update(session_id)
+            .finalize();
+        let (r, s) = sign(d, scalar(0x1234_5678_9abc_def0), &digest);
+
+        let mut payload = [0; 108];
+        payload[2..4].copy_from_slice(&(messages.len() as u16).to_le_bytes());
+        payload[4..36].copy_from_slice(&final_hash);
+        payload[36..60].copy_from_slice(&session_id);
+        payload[60..84].copy_from_slice(&r);
+        payload[84..108].copy_from_slice(&s);
+        payload
+    }
+
+    #[test]
+    fn verify_stream() {
+        let d = scalar(0x0bad_cafe_f00d);
+        let key = VerifyingKey::from_affine((ProjectivePoint::GENERATOR * d).to_affine()).unwrap();
+        let messages: [(u8, u8, &[u8]); 3] = [
+            (0x01, 0x20, &[0x10, 0x27, 0x00, 0x00]),
+            (0x01, 0x07, &[0x42; 92]),
+            (0x0a, 0x04, &[0x30; 40]),
+        ];
+        let sign = sign_payload(&messages, d, SESSION_ID);
+        assert!(SecEcsignRef::validate(&sign).is_ok());
+
+        let mut verifier = SecEcsignVerifier::new(key, SESSION_ID);
+        for (class_id, msg_id, payload) in messages {
+            verifier.update(class_id, msg_id, payload);
+        }
+        assert_eq!(verifier.verify(&SecEcsignRef(&sign)), Ok(()));
+
+        // The verifier starts a new stream after each signature
+        assert_eq!(
+            verifier.verify(&SecEcsignRef(&sign)),
+            Err(SecVerifyError::MessageCount {
+                expected: 3,
+                got: 0
+            })
+        );
+
+        // Tampered message
+        verifier.update(0x01, 0x20, &[0x11, 0x27, 0x00, 0x00]);
+        verifier.update(messages[1].0, messages[1].1, messages[1].2);
+        verifier.update(messages[2].0, messages[2].1, messages[2].2);
+        assert_eq!(
+            verifier.verify(&SecEcsignRef(&sign)),
+            Err(SecVerifyError::HashMismatch)
+        );
+
+        // Signed by another key
+        let sign = sign_payload(&messages, d + Scalar::ONE, SESSION_ID);
+        for (class_id, msg_id, payload) in messages {
+            verifier.update(class_id, msg_id, payload);
+        }
+        assert_eq!(
+            verifier.verify(&SecEcsignRef(&sign)),
+            Err(SecVerifyError::InvalidSignature)
+        );
+
+        // Signed for another session
+        let sign = sign_payload(&messages, d, [0; 24]);
+        for (class_id, msg_id, payload) in messages {
+            verifier.update(class_id, msg_id, payload);
+        }
+        assert_eq!(
+            verifier.verify(&SecEcsignRef(&sign)),
+            Err(SecVerifyError::SessionIdMismatch)
+        );
+    }
+}
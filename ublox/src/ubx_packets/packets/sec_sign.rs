@@ -0,0 +1,216 @@
+//! SEC-SIGN: Signature of the message stream
+//!
+//! Hash of the messages output since the previous signature, combined with
+//! a secret seed shared by the receiver and the host.
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+#[cfg(feature = "sec-verify")]
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "sec-verify")]
+use crate::{constants::UBX_SYNC_CHAR_1, constants::UBX_SYNC_CHAR_2, ubx_packets::UbxChecksumCalc};
+use crate::{error::ParserError, UbxPacketMeta};
+#[cfg(feature = "sec-verify")]
+use crate::{error::SecVerifyError, UbxPacket};
+use ublox_derive::ubx_packet_recv;
+
+/// Signature of the message stream
+#[ubx_packet_recv]
+#[ubx(class = 0x27, id = 0x01, fixed_payload_len = 40)]
+struct SecSign {
+    /// Message version (0x01 for this version)
+    version: u8,
+
+    reserved1: [u8; 3],
+
+    /// Class of the last signed message
+    class_id: u8,
+
+    /// ID of the last signed message
+    msg_id: u8,
+
+    /// Checksum of the last signed message, as found in its frame
+    checksum: u16,
+
+    /// SHA-256 hash of the signed messages followed by the seed
+    hash: [u8; 32],
+}
+
+/// Feeds the exact UBX frame of a packet to `hasher`, returning its checksum
+///
+/// The frame is rebuilt from the class, ID and payload, which yields the bytes
+/// that were received since the parser only accepts frames with a valid checksum.
+#[cfg(feature = "sec-verify")]
+pub(crate) fn hash_frame(hasher: &mut Sha256, class_id: u8, msg_id: u8, payload: &[u8]) -> u16 {
+    let len = (payload.len() as u16).to_le_bytes();
+    let header = [
+        UBX_SYNC_CHAR_1,
+        UBX_SYNC_CHAR_2,
+        class_id,
+        msg_id,
+        len[0],
+        len[1],
+    ];
+    let mut checksum = UbxChecksumCalc::default();
+    checksum.update(&header[2..]);
+    checksum.update(payload);
+    let (ck_a, ck_b) = checksum.result();
+
+    hasher.update(header);
+    hasher.update(payload);
+    hasher.update([ck_a, ck_b]);
+    u16::from_le_bytes([ck_a, ck_b])
+}
+
+/// Verifies `SecSign` messages against the stream of messages preceding them
+///
+/// Every packet returned by the parser must be passed to [SecSignVerifier::update_packet]
+/// (or [SecSignVerifier::update]) in order, except the `SecSign` messages themselves,
+/// which are passed to [SecSignVerifier::verify].
+#[cfg(feature = "sec-verify")]
+#[derive(Clone)]
+pub struct SecSignVerifier<'a> {
+    seed: &'a [u8],
+    hasher: Sha256,
+    last: Option<(u8, u8, u16)>,
+}
+
+#[cfg(feature = "sec-verify")]
+impl<'a> SecSignVerifier<'a> {
+    /// Verifier using the secret `seed` configured on the receiver
+    pub fn new(seed: &'a [u8]) -> Self {
+        Self {
+            seed,
+            hasher: Sha256::new(),
+            last: None,
+        }
+    }
+
+    /// Adds a message, given by its class, ID and payload, to the signed stream
+    pub fn update(&mut self, class_id: u8, msg_id: u8, payload: &[u8]) {
+        let checksum = hash_frame(&mut self.hasher, class_id, msg_id, payload);
+        self.last = Some((class_id, msg_id, checksum));
+    }
+
+    /// Adds a packet returned by the parser to the signed stream
+    pub fn update_packet(&mut self, packet: &UbxPacket<'_>) {
+        let (class_id, msg_id) = packet.class_and_msg_id();
+        self.update(class_id, msg_id, packet.payload());
+    }
+
+    /// Checks `sign` against the messages added since the previous signature,
+    /// and starts a new stream
+    pub fn verify(&mut self, sign: &SecSignRef<'_>) -> Result<(), SecVerifyError> {
+        let mut hasher = core::mem::take(&mut self.hasher);
+        let last = self.last.take();
+
+        if last != Some((sign.class_id(), sign.msg_id(), sign.checksum())) {
+            return Err(SecVerifyError::LastMessageMismatch);
+        }
+        hasher.update(self.seed);
+        if hasher.finalize().as_slice() != sign.hash() {
+            return Err(SecVerifyError::HashMismatch);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "sec-verify"))]
+mod tests {
+    use super::*;
+
+    const SEED: [u8; 8] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+
+    fn sign_payload(messages: &[(u8, u8, &[u8])], seed: &[u8]) -> [u8; 40] {
+        let mut hasher = Sha256::new();
+        let mut checksum = 0;
+        for (class_id, msg_id, payload) in messages {
+            checksum = hash_frame(&mut hasher, *class_id, *msg_id, payload);
+        }
+        hasher.update(seed);
+
+        let (class_id, msg_id, _) = messages[messages.len() - 1];
+        let mut payload = [0; 40];
+        payload[0] = 0x01;
+        payload[4] = class_id;
+        payload[5] = msg_id;
+        payload[6..8].copy_from_slice(&checksum.to_le_bytes());
+        payload[8..].copy_from_slice(&hasher.finalize());
+        payload
+    }
+
+    #[test]
+    fn verify_stream() {
+        let messages: [(u8, u8, &[u8]); 2] = [
+            (0x01, 0x20, &[0x10, 0x27, 0x00, 0x00]),
+            (0x0a, 0x04, &[0x30; 40]),
+        ];
+        let sign = sign_payload(&messages, &SEED);
+        assert!(SecSignRef::validate(&sign).is_ok());
+
+        let mut verifier = SecSignVerifier::new(&SEED);
+        for (class_id, msg_id, payload) in messages {
+            verifier.update(class_id, msg_id, payload);
+        }
+        assert_eq!(verifier.verify(&SecSignRef(&sign)), Ok(()));
+
+        // The next signature only covers messages after this one
+        verifier.update(0x01, 0x20, &[0x11, 0x27, 0x00, 0x00]);
+        assert_eq!(
+            verifier.verify(&SecSignRef(&sign)),
+            Err(SecVerifyError::LastMessageMismatch)
+        );
+
+        // Wrong seed
+        let sign = sign_payload(&messages, &[0; 8]);
+        for (class_id, msg_id, payload) in messages {
+            verifier.update(class_id, msg_id, payload);
+        }
+        assert_eq!(
+            verifier.verify(&SecSignRef(&sign)),
+            Err(SecVerifyError::HashMismatch)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "ubx_proto23")]
+    fn verify_parsed_frames() {
+        use crate::{proto23::Proto23, ParserBuilder};
+
+        let mut stream = vec![
+            0xb5, 0x62, 0x01, 0x20, 0x10, 0x00, 0x10, 0x27, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x12, 0x07, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let (ck_a, ck_b) = {
+            let mut checksum = UbxChecksumCalc::default();
+            checksum.update(&stream[2..]);
+            checksum.result()
+        };
+        stream.extend([ck_a, ck_b]);
+
+        // Signed over the raw bytes, independently of the parser
+        let mut sign = [0; 40];
+        sign[0] = 0x01;
+        sign[4..6].copy_from_slice(&[0x01, 0x20]);
+        sign[6..8].copy_from_slice(&[ck_a, ck_b]);
+        sign[8..].copy_from_slice(
+            &Sha256::new()
+                .chain_update(&stream)
+                .chain_update(SEED)
+                .finalize(),
+        );
+
+        let mut verifier = SecSignVerifier::new(&SEED);
+        let mut parser = ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .with_fixed_buffer::<1024>();
+        let mut it = parser.consume_ubx(&stream);
+        while let Some(packet) = it.next() {
+            verifier.update_packet(&packet.unwrap());
+        }
+        assert_eq!(verifier.verify(&SecSignRef(&sign)), Ok(()));
+    }
+}
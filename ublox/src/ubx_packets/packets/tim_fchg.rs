@@ -0,0 +1,44 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv;
+
+/// Oscillator frequency changed notification
+///
+/// Reports the frequency changes applied to the disciplined oscillators.
+#[ubx_packet_recv]
+#[ubx(class = 0x0d, id = 0x16, fixed_payload_len = 32)]
+struct TimFchg {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    reserved1: [u8; 3],
+
+    /// GPS time of week of the navigation epoch from which the sensor time tag is taken (ms)
+    itow: u32,
+
+    /// Frequency increment of the internal oscillator (ppb)
+    #[ubx(map_type = f64, scale = 0.00390625, alias = int_delta_freq_ppb)] // 2^-8
+    int_delta_freq: i32,
+
+    /// Uncertainty of the internal oscillator frequency increment (ppb)
+    #[ubx(map_type = f64, scale = 0.00390625, alias = int_delta_freq_unc_ppb)]
+    int_delta_freq_unc: u32,
+
+    /// Current raw DAC setting commanded to the internal oscillator
+    int_raw: u32,
+
+    /// Frequency increment of the external oscillator (ppb)
+    #[ubx(map_type = f64, scale = 0.00390625, alias = ext_delta_freq_ppb)]
+    ext_delta_freq: i32,
+
+    /// Uncertainty of the external oscillator frequency increment (ppb)
+    #[ubx(map_type = f64, scale = 0.00390625, alias = ext_delta_freq_unc_ppb)]
+    ext_delta_freq_unc: u32,
+
+    /// Current raw DAC setting commanded to the external oscillator
+    ext_raw: u32,
+}
@@ -0,0 +1,79 @@
+use bitflags::bitflags;
+
+use crate::{
+    ubx_checksum, ubx_packets::packets::ScaleBack, MemWriter, MemWriterError, OscillatorId,
+    UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_send};
+
+/// Host oscillator control
+///
+/// Lets the host steer an oscillator directly, either with a frequency offset or
+/// with a raw DAC value. Only accepted while the oscillator is under host control.
+#[ubx_packet_send]
+#[ubx(
+    class = 0x0d,
+    id = 0x17,
+    fixed_payload_len = 8,
+    flags = "default_for_builder"
+)]
+struct TimHoc {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Oscillator to control, see [OscillatorId]
+    #[ubx(map_type = OscillatorId)]
+    osc_id: u8,
+
+    /// How `value` is to be applied, see [TimHocFlags]
+    #[ubx(map_type = TimHocFlags)]
+    flags: u8,
+
+    reserved1: u8,
+
+    /// Required frequency offset (ppb) or raw output value, depending on [TimHocFlags::RAW]
+    value: i32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(into_raw, rest_reserved)]
+bitflags! {
+    /// Flags for `TimHoc`
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct TimHocFlags: u8 {
+        /// `value` is a raw DAC value instead of a frequency offset
+        const RAW = 0x01;
+        /// `value` is relative to the current setting instead of absolute
+        const DIFFERENCE = 0x02;
+    }
+}
+
+impl TimHocBuilder {
+    /// Sets `value` to a frequency offset given in ppb, clearing [TimHocFlags::RAW]
+    pub fn with_freq_offset_ppb(mut self, ppb: f64) -> Self {
+        self.flags.remove(TimHocFlags::RAW);
+        self.value = ScaleBack::<f64>(256.0).as_i32(ppb);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_frequency_offset() {
+        let bytes = TimHocBuilder {
+            osc_id: OscillatorId::External,
+            flags: TimHocFlags::RAW | TimHocFlags::DIFFERENCE,
+            ..Default::default()
+        }
+        .with_freq_offset_ppb(-1.5)
+        .into_packet_bytes();
+
+        assert_eq!(
+            &bytes[..14],
+            [0xb5, 0x62, 0x0d, 0x17, 0x08, 0x00, 0x00, 0x01, 0x02, 0x00, 0x80, 0xfe, 0xff, 0xff]
+        );
+    }
+}
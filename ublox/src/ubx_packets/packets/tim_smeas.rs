@@ -0,0 +1,193 @@
+#[allow(unused_imports, reason = "It is only unused in some feature sets")]
+use crate::FieldIter;
+use bitflags::bitflags;
+#[cfg(feature = "serde")]
+use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv};
+
+/// Source measurement
+///
+/// Phase and frequency offsets of the frequency and time sources of a timing
+/// receiver, relative to its internal reference.
+#[ubx_packet_recv]
+#[ubx(class = 0x0d, id = 0x13, max_payload_len = 6132)] // 12 + 255 * 24
+struct TimSmeas {
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Number of measurements in repeated block
+    num_meas: u8,
+
+    reserved1: [u8; 2],
+
+    /// Time of the week (ms)
+    itow: u32,
+
+    reserved2: [u8; 4],
+
+    #[ubx(
+        map_type = TimSmeasIter,
+        from = TimSmeasIter::new,
+        is_valid = TimSmeasIter::is_valid,
+        may_fail,
+        get_as_ref,
+    )]
+    measurements: [u8; 0],
+}
+
+/// Frequency or time source of a timing receiver
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TimSmeasSource {
+    InternalOscillator = 0,
+    ExternalOscillator = 1,
+    Gnss = 3,
+    ExtInt0 = 4,
+    ExtInt1 = 5,
+    /// Time reference provided by the host
+    HostTimeRef = 6,
+    /// Frequency reference provided by the host
+    HostFreqRef = 7,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of a `TimSmeas` measurement
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct TimSmeasFlags: u8 {
+        /// Frequency measurement is valid
+        const FREQ_VALID = 0x01;
+        /// Phase measurement is valid
+        const PHASE_VALID = 0x02;
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimSmeasIter<'a>(core::slice::ChunksExact<'a, u8>);
+
+impl<'a> TimSmeasIter<'a> {
+    const BLOCK_SIZE: usize = 24;
+
+    fn new(data: &'a [u8]) -> Self {
+        Self(data.chunks_exact(Self::BLOCK_SIZE))
+    }
+
+    fn is_valid(bytes: &[u8]) -> bool {
+        bytes.len().is_multiple_of(Self::BLOCK_SIZE)
+    }
+}
+
+impl<'a> core::iter::Iterator for TimSmeasIter<'a> {
+    type Item = TimSmeasMeasRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(TimSmeasMeasRef)
+    }
+}
+
+/// Measurement of a single source
+#[ubx_packet_recv]
+#[ubx(class = 0x0d, id = 0x13, fixed_payload_len = 24)]
+struct TimSmeasMeas {
+    /// Source identifier, see [TimSmeasSource]
+    #[ubx(map_type = TimSmeasSource)]
+    source_id: u8,
+
+    /// Validity flags, see [TimSmeasFlags]
+    #[ubx(map_type = TimSmeasFlags)]
+    flags: u8,
+
+    /// Sub-nanosecond phase offset (ns)
+    #[ubx(map_type = f64, scale = 0.00390625, alias = phase_offset_frac_ns)] // 2^-8
+    phase_offset_frac: i8,
+
+    /// Sub-nanosecond phase uncertainty (ns)
+    #[ubx(map_type = f64, scale = 0.00390625, alias = phase_unc_frac_ns)]
+    phase_unc_frac: u8,
+
+    /// Phase offset (ns)
+    phase_offset: i32,
+
+    /// Phase uncertainty (ns)
+    phase_unc: u32,
+
+    reserved3: [u8; 4],
+
+    /// Frequency offset (ppb)
+    #[ubx(map_type = f64, scale = 0.00390625, alias = freq_offset_ppb)]
+    freq_offset: i32,
+
+    /// Frequency uncertainty (ppb)
+    #[ubx(map_type = f64, scale = 0.00390625, alias = freq_unc_ppb)]
+    freq_unc: u32,
+}
+
+macro_rules! impl_phase {
+    ($type:ty) => {
+        impl $type {
+            /// Phase offset including the sub-nanosecond part (ns)
+            pub fn phase_offset_ns(&self) -> f64 {
+                f64::from(self.phase_offset()) + self.phase_offset_frac_ns()
+            }
+
+            /// Phase uncertainty including the sub-nanosecond part (ns)
+            pub fn phase_unc_ns(&self) -> f64 {
+                f64::from(self.phase_unc()) + self.phase_unc_frac_ns()
+            }
+        }
+    };
+}
+
+impl_phase!(TimSmeasMeasRef<'_>);
+impl_phase!(TimSmeasMeasOwned);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measurements() {
+        let mut payload = [0u8; 12 + 2 * 24];
+        payload[1] = 2; // num_meas
+        payload[4..8].copy_from_slice(&1_000u32.to_le_bytes());
+
+        let meas = &mut payload[12..36];
+        meas[0] = 0; // internal oscillator
+        meas[1] = 0x03;
+        meas[2] = (-128i8) as u8; // -0.5 ns
+        meas[3] = 64; // 0.25 ns
+        meas[4..8].copy_from_slice(&(-12i32).to_le_bytes());
+        meas[8..12].copy_from_slice(&3u32.to_le_bytes());
+        meas[16..20].copy_from_slice(&(-512i32).to_le_bytes()); // -2 ppb
+        meas[20..24].copy_from_slice(&128u32.to_le_bytes()); // 0.5 ppb
+
+        payload[36] = 3; // GNSS
+        payload[37] = 0x02;
+        assert!(TimSmeasRef::validate(&payload).is_ok());
+
+        let packet = TimSmeasRef(&payload);
+        assert_eq!(packet.itow(), 1_000);
+
+        let mut it = packet.measurements();
+        let m = it.next().unwrap();
+        assert_eq!(m.source_id(), TimSmeasSource::InternalOscillator);
+        assert_eq!(
+            m.flags(),
+            TimSmeasFlags::FREQ_VALID | TimSmeasFlags::PHASE_VALID
+        );
+        assert!((m.phase_offset_ns() + 12.5).abs() < 1e-9);
+        assert!((m.phase_unc_ns() - 3.25).abs() < 1e-9);
+        assert!((m.freq_offset_ppb() + 2.0).abs() < 1e-9);
+        assert!((m.freq_unc_ppb() - 0.5).abs() < 1e-9);
+
+        let m = it.next().unwrap();
+        assert_eq!(m.source_id(), TimSmeasSource::Gnss);
+        assert_eq!(m.flags(), TimSmeasFlags::PHASE_VALID);
+        assert!(it.next().is_none());
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, OscillatorId, UbxPacketCreator,
+    UbxPacketMeta,
+};
+use ublox_derive::{ubx_packet_recv, ubx_packet_send};
+
+/// VCO calibration results
+///
+/// Output at the end of a calibration started with [TimVcocalCommand].
+#[ubx_packet_recv]
+#[ubx(class = 0x0d, id = 0x15, fixed_payload_len = 12)]
+struct TimVcocal {
+    /// Message type (0x03 for results)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Calibrated oscillator, see [OscillatorId]
+    #[ubx(map_type = OscillatorId, may_fail)]
+    osc_id: u8,
+
+    reserved1: [u8; 3],
+
+    /// Relative uncertainty of the VCO gain (1 = 100%)
+    #[ubx(map_type = f64, scale = 1.52587890625e-5, alias = gain_uncertainty_ratio)] // 2^-16
+    gain_uncertainty: u16,
+
+    /// Calibrated VCO gain (ppb per raw LSB)
+    #[ubx(map_type = f64, scale = 1.52587890625e-5, alias = gain_vco_ppb)] // 2^-16
+    gain_vco: i32,
+}
+
+/// Stop the running VCO calibration
+#[ubx_packet_send]
+#[ubx(
+    class = 0x0d,
+    id = 0x15,
+    fixed_payload_len = 1,
+    flags = "default_for_builder"
+)]
+struct TimVcocalStop {
+    /// Message type (0x00 to stop the calibration)
+    msg_type: u8,
+}
+
+/// Start a VCO calibration
+///
+/// The receiver steps the oscillator control value between `raw0` and `raw1`
+/// and reports the measured gain in [TimVcocal]. Use `TimVcocalCommandBuilder::default()`
+/// which sets the message type for this command.
+#[ubx_packet_send]
+#[ubx(class = 0x0d, id = 0x15, fixed_payload_len = 12)]
+struct TimVcocalCommand {
+    /// Message type (0x02 for the extended calibration command)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Oscillator to calibrate, see [OscillatorId]
+    #[ubx(map_type = OscillatorId)]
+    osc_id: u8,
+
+    /// Reference source for the calibration, see the `TimSmeas` source identifiers
+    src_id: u8,
+
+    reserved1: [u8; 2],
+
+    /// First value used for calibration
+    raw0: u16,
+
+    /// Second value used for calibration
+    raw1: u16,
+
+    /// Maximum step size to be used (raw value per second)
+    max_step_size: u16,
+}
+
+impl Default for TimVcocalCommandBuilder {
+    fn default() -> Self {
+        Self {
+            msg_type: 2,
+            version: 0,
+            osc_id: OscillatorId::default(),
+            src_id: 0,
+            reserved1: [0; 2],
+            raw0: 0,
+            raw1: 0,
+            max_step_size: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_and_results() {
+        let bytes = TimVcocalCommandBuilder {
+            osc_id: OscillatorId::External,
+            src_id: 3,
+            raw0: 0x1000,
+            raw1: 0x2000,
+            max_step_size: 0x0100,
+            ..Default::default()
+        }
+        .into_packet_bytes();
+        assert_eq!(
+            &bytes[6..18],
+            [0x02, 0x00, 0x01, 0x03, 0x00, 0x00, 0x00, 0x10, 0x00, 0x20, 0x00, 0x01]
+        );
+        assert_eq!(TimVcocalStopBuilder::default().into_packet_bytes()[6], 0x00);
+
+        let payload = [
+            0x03, 0x00, 0x01, 0x00, 0x00, 0x00, // type, version, osc_id, reserved1
+            0x00, 0x40, // gain_uncertainty: 0.25
+            0x00, 0x80, 0xfe, 0xff, // gain_vco: -1.5 ppb
+        ];
+        assert!(TimVcocalRef::validate(&payload).is_ok());
+        let packet = TimVcocalRef(&payload);
+        assert_eq!(packet.osc_id(), OscillatorId::External);
+        assert!((packet.gain_uncertainty_ratio() - 0.25).abs() < 1e-9);
+        assert!((packet.gain_vco_ppb() + 1.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,70 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_packet_recv};
+
+/// Sourced time verification
+///
+/// Compares the time the receiver was aided with (RTC or host) against the
+/// time derived from the navigation solution.
+#[ubx_packet_recv]
+#[ubx(class = 0x0d, id = 0x06, fixed_payload_len = 20)]
+struct TimVrfy {
+    /// Integer ms of time of week
+    itow: i32,
+
+    /// Sub-millisecond part of time of week (ns)
+    frac: i32,
+
+    /// Integer ms of the difference between aiding time and navigation time
+    delta_ms: i32,
+
+    /// Sub-millisecond part of the difference between aiding time and navigation time (ns)
+    delta_ns: i32,
+
+    /// Week number
+    wno: u16,
+
+    /// Aiding time source, see [TimVrfySource]
+    #[ubx(map_type = TimVrfySource, from = TimVrfySource::from_flags)]
+    flags: u8,
+
+    reserved1: u8,
+}
+
+/// Source of the time the receiver was aided with
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TimVrfySource {
+    /// No time aiding done
+    NoAiding = 0,
+    /// Source was RTC
+    Rtc = 2,
+    /// Source was AID-INI or MGA-INI
+    AidIni = 3,
+}
+
+impl TimVrfySource {
+    fn from_flags(flags: u8) -> Self {
+        Self::from(flags & 0x07)
+    }
+}
+
+macro_rules! impl_delta {
+    ($type:ty) => {
+        impl $type {
+            /// Difference between aiding time and navigation time (ns)
+            pub fn delta(&self) -> i64 {
+                i64::from(self.delta_ms()) * 1_000_000 + i64::from(self.delta_ns())
+            }
+        }
+    };
+}
+
+impl_delta!(TimVrfyRef<'_>);
+impl_delta!(TimVrfyOwned);
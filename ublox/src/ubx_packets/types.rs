@@ -117,12 +117,72 @@ pub(crate) fn datetime_from_components(
     let time = NaiveTime::from_hms_opt(u32::from(hour), u32::from(min), u32::from(sec))
         .ok_or(DateTimeError::InvalidTime)?;
 
-    const NANOS_LIM: u32 = 1_000_000_000;
+    check_nanos(nanos)?;
+
+    let dt = NaiveDateTime::new(date, time) + chrono::Duration::nanoseconds(i64::from(nanos));
+    Ok(DateTime::from_naive_utc_and_offset(dt, Utc))
+}
+
+/// Start of week 0 of GPS time, also used by QZSS
+pub(crate) const GPS_EPOCH: (i32, u32, u32) = (1980, 1, 6);
+/// Start of week 0 of Galileo System Time (GST)
+pub(crate) const GALILEO_EPOCH: (i32, u32, u32) = (1999, 8, 22);
+/// Start of week 0 of BeiDou Time (BDT)
+pub(crate) const BEIDOU_EPOCH: (i32, u32, u32) = (2006, 1, 1);
+/// Start of week 0 of NavIC (IRNSS) System Time
+pub(crate) const NAVIC_EPOCH: (i32, u32, u32) = (1999, 8, 22);
+
+const SECONDS_PER_WEEK: u32 = 604_800;
+const NANOS_LIM: u32 = 1_000_000_000;
+
+fn check_nanos(nanos: i32) -> Result<(), DateTimeError> {
     if (nanos.wrapping_abs() as u32) >= NANOS_LIM {
-        return Err(DateTimeError::InvalidNanoseconds);
+        Err(DateTimeError::InvalidNanoseconds)
+    } else {
+        Ok(())
     }
+}
 
-    let dt = NaiveDateTime::new(date, time) + chrono::Duration::nanoseconds(i64::from(nanos));
+/// Helper function to convert a GNSS week number and time of week to a date & time
+/// expressed in the time scale of that GNSS (so without applying leap seconds).
+///
+/// `epoch` is the (year, month, day) at which week 0 of the GNSS starts.
+pub(crate) fn gnss_time_from_week_tow(
+    epoch: (i32, u32, u32),
+    week: i16,
+    tow: u32,
+    ftow: i32,
+) -> Result<NaiveDateTime, DateTimeError> {
+    if week < 0 {
+        return Err(DateTimeError::InvalidDate);
+    }
+    if tow >= SECONDS_PER_WEEK {
+        return Err(DateTimeError::InvalidTime);
+    }
+    check_nanos(ftow)?;
+
+    let (year, month, day) = epoch;
+    let start = NaiveDate::from_ymd_opt(year, month, day)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .ok_or(DateTimeError::InvalidDate)?;
+
+    Ok(start
+        + chrono::Duration::weeks(i64::from(week))
+        + chrono::Duration::seconds(i64::from(tow))
+        + chrono::Duration::nanoseconds(i64::from(ftow)))
+}
+
+/// Helper function to convert a GNSS week number, time of week and leap seconds
+/// (GNSS time - UTC) to DateTime<Utc>
+pub(crate) fn datetime_from_week_tow(
+    epoch: (i32, u32, u32),
+    week: i16,
+    tow: u32,
+    ftow: i32,
+    leap_s: i8,
+) -> Result<DateTime<Utc>, DateTimeError> {
+    let gnss_time = gnss_time_from_week_tow(epoch, week, tow, ftow)?;
+    let dt = gnss_time - chrono::Duration::seconds(i64::from(leap_s));
     Ok(DateTime::from_naive_utc_and_offset(dt, Utc))
 }
 
@@ -151,3 +211,38 @@ where
         serializer.collect_seq(self.0.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn week_tow_epochs_agree_on_utc() {
+        let expected = Utc.with_ymd_and_hms(2024, 2, 7, 23, 59, 42).unwrap();
+
+        // GPS and GST are aligned, BDT lags them by 14 s
+        let gps = datetime_from_week_tow(GPS_EPOCH, 2300, 345_600, 0, 18).unwrap();
+        let gst = datetime_from_week_tow(GALILEO_EPOCH, 1276, 345_600, 0, 18).unwrap();
+        let bdt = datetime_from_week_tow(BEIDOU_EPOCH, 944, 345_586, 0, 4).unwrap();
+
+        assert_eq!(gps, expected);
+        assert_eq!(gst, expected);
+        assert_eq!(bdt, expected);
+    }
+
+    #[test]
+    fn week_tow_out_of_range() {
+        assert!(matches!(
+            gnss_time_from_week_tow(GPS_EPOCH, -1, 0, 0),
+            Err(DateTimeError::InvalidDate)
+        ));
+        assert!(matches!(
+            gnss_time_from_week_tow(GPS_EPOCH, 0, SECONDS_PER_WEEK, 0),
+            Err(DateTimeError::InvalidTime)
+        ));
+        assert!(matches!(
+            gnss_time_from_week_tow(GPS_EPOCH, 0, 0, 1_000_000_000),
+            Err(DateTimeError::InvalidNanoseconds)
+        ));
+    }
+}
@@ -0,0 +1,635 @@
+//! u-center configuration files
+//!
+//! u-center saves the receiver configuration as text files, with one entry
+//! per line:
+//!
+//! ```text
+//! MON-VER - 0A 04 28 00 52 4F 4D 20 ...
+//! CFG-RATE - 06 08 06 00 E8 03 01 00 01 00
+//! [del]
+//! Flash CFG-RATE-MEAS
+//! [set]
+//!   RAM CFG-RATE-MEAS 100
+//! CFG-VALGET - 06 8B 0A 00 01 00 00 00 01 00 21 30 64 00
+//! ```
+//!
+//! Legacy (u-blox 8 and older) files only hold UBX messages as hex bytes,
+//! without sync characters and checksum. Generation 9 files also hold
+//! configuration values, optionally prefixed with the layer they are stored
+//! in, to set or, in the `[del]` section, to delete. Values are integers, in
+//! decimal or `0x` hexadecimal, or floating point numbers. Text after a `#` is
+//! a comment.
+//!
+//! Legacy CFG messages are mapped to their packet builders, or for messages
+//! the crate can also receive, to their typed views, by
+//! [UcenterEntry::legacy_cfg].
+
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use crate::{
+    cfg_profile::{decode, encode, ProfileValue},
+    cfg_val::{CfgKey, CfgKeyInfo, CfgVal},
+    constants::{UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2},
+    error::{ParserError, ProfileError, UcenterError},
+    packets::{
+        cfg_dat::{CfgDatRef, CfgDatSetBuilder},
+        cfg_dgnss::CfgDgnssRef,
+        cfg_hnr::CfgHnrRef,
+        cfg_logfilter::CfgLogfilterRef,
+        cfg_msg::{CfgMsgAllPortsBuilder, CfgMsgSinglePortBuilder},
+        cfg_nav5::CfgNav5Ref,
+        cfg_nmea::CfgNmeaRef,
+        cfg_prt::{CfgPrtI2cRef, CfgPrtSpiRef, CfgPrtUartRef},
+        cfg_rate::{AlignmentToReferenceTime, CfgRateBuilder},
+        cfg_usb::CfgUsbRef,
+        cfg_val::{
+            CfgLayerGet, CfgLayerSet, CfgValDelBuilder, CfgValGetResponseRef, CfgValSetBuilder,
+            MAX_CFG_KEYS,
+        },
+    },
+    ubx_checksum, UbxProtocol,
+};
+
+/// Entry of a u-center configuration file
+#[derive(Debug, Clone, PartialEq)]
+pub enum UcenterEntry {
+    /// Configuration value to set, `layers` is empty if the line has no layer
+    Set { layers: CfgLayerSet, val: CfgVal },
+    /// Configuration value to delete, `layers` is empty if the line has no
+    /// layer
+    Delete { layers: CfgLayerSet, key: CfgKey },
+    /// UBX message, such as a legacy CFG message or a CFG-VALGET response
+    Message { class: u8, id: u8, payload: Vec<u8> },
+}
+
+impl UcenterEntry {
+    /// The message as a UBX frame, with sync characters and checksum
+    pub fn frame(&self) -> Option<Vec<u8>> {
+        let Self::Message { class, id, payload } = self else {
+            return None;
+        };
+        let mut frame = Vec::with_capacity(payload.len() + 8);
+        frame.extend([UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2, *class, *id]);
+        frame.extend((payload.len() as u16).to_le_bytes());
+        frame.extend_from_slice(payload);
+        let (ck_a, ck_b) = ubx_checksum(&frame[2..]);
+        frame.extend([ck_a, ck_b]);
+        Some(frame)
+    }
+
+    /// The message as a packet of protocol `P`
+    pub fn packet<P: UbxProtocol>(&self) -> Option<Result<P::PacketRef<'_>, ParserError>> {
+        match self {
+            Self::Message { class, id, payload } => Some(P::match_packet(*class, *id, payload)),
+            _ => None,
+        }
+    }
+
+    /// The message as a typed legacy CFG message: messages the crate can
+    /// only send as their builder, the others as their typed view. `None`
+    /// for other messages, and for CFG messages that are not valid.
+    pub fn legacy_cfg(&self) -> Option<LegacyCfg<'_>> {
+        let Self::Message {
+            class: 0x06,
+            id,
+            payload,
+        } = self
+        else {
+            return None;
+        };
+        let payload = payload.as_slice();
+        let u16_at = |i: usize| u16::from_le_bytes([payload[i], payload[i + 1]]);
+        let f32_at = |i: usize| f32::from_le_bytes(payload[i..i + 4].try_into().unwrap());
+        let cfg = match (*id, payload.len()) {
+            (0x01, 3) => LegacyCfg::MsgSinglePort(CfgMsgSinglePortBuilder {
+                msg_class: payload[0],
+                msg_id: payload[1],
+                rate: payload[2],
+            }),
+            (0x01, 8) => LegacyCfg::MsgAllPorts(CfgMsgAllPortsBuilder {
+                msg_class: payload[0],
+                msg_id: payload[1],
+                rates: payload[2..].try_into().unwrap(),
+            }),
+            (0x08, 6) => LegacyCfg::Rate(CfgRateBuilder {
+                measure_rate_ms: u16_at(0),
+                nav_rate: u16_at(2),
+                time_ref: match u16_at(4) {
+                    0 => AlignmentToReferenceTime::Utc,
+                    1 => AlignmentToReferenceTime::Gps,
+                    2 => AlignmentToReferenceTime::Glo,
+                    3 => AlignmentToReferenceTime::Bds,
+                    4 => AlignmentToReferenceTime::Gal,
+                    _ => return None,
+                },
+            }),
+            (0x06, 44) => LegacyCfg::DatSet(CfgDatSetBuilder {
+                maj_a: f64::from_le_bytes(payload[0..8].try_into().unwrap()),
+                flat: f64::from_le_bytes(payload[8..16].try_into().unwrap()),
+                d_x: f32_at(16),
+                d_y: f32_at(20),
+                d_z: f32_at(24),
+                rot_x: f32_at(28),
+                rot_y: f32_at(32),
+                rot_z: f32_at(36),
+                scale: f32_at(40),
+            }),
+            (0x00, _) if CfgPrtUartRef::validate(payload).is_ok() => {
+                LegacyCfg::PrtUart(CfgPrtUartRef(payload))
+            },
+            (0x00, _) if CfgPrtSpiRef::validate(payload).is_ok() => {
+                LegacyCfg::PrtSpi(CfgPrtSpiRef(payload))
+            },
+            (0x00, _) if CfgPrtI2cRef::validate(payload).is_ok() => {
+                LegacyCfg::PrtI2c(CfgPrtI2cRef(payload))
+            },
+            (0x06, _) if CfgDatRef::validate(payload).is_ok() => LegacyCfg::Dat(CfgDatRef(payload)),
+            (0x17, _) if CfgNmeaRef::validate(payload).is_ok() => {
+                LegacyCfg::Nmea(CfgNmeaRef(payload))
+            },
+            (0x1b, _) if CfgUsbRef::validate(payload).is_ok() => LegacyCfg::Usb(CfgUsbRef(payload)),
+            (0x24, _) if CfgNav5Ref::validate(payload).is_ok() => {
+                LegacyCfg::Nav5(CfgNav5Ref(payload))
+            },
+            (0x47, _) if CfgLogfilterRef::validate(payload).is_ok() => {
+                LegacyCfg::Logfilter(CfgLogfilterRef(payload))
+            },
+            (0x5c, _) if CfgHnrRef::validate(payload).is_ok() => LegacyCfg::Hnr(CfgHnrRef(payload)),
+            (0x70, _) if CfgDgnssRef::validate(payload).is_ok() => {
+                LegacyCfg::Dgnss(CfgDgnssRef(payload))
+            },
+            _ => return None,
+        };
+        Some(cfg)
+    }
+
+    /// Configuration values of a CFG-VALGET response
+    fn valget_values(&self) -> Option<Vec<CfgVal>> {
+        match self {
+            Self::Message {
+                class: 0x06,
+                id: 0x8b,
+                payload,
+            } if payload.first() == Some(&0x01)
+                && CfgValGetResponseRef::validate(payload).is_ok() =>
+            {
+                Some(CfgValGetResponseRef(payload).cfg_data().collect())
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Legacy CFG message of a u-center configuration file, see
+/// [UcenterEntry::legacy_cfg]
+pub enum LegacyCfg<'a> {
+    MsgSinglePort(CfgMsgSinglePortBuilder),
+    MsgAllPorts(CfgMsgAllPortsBuilder),
+    Rate(CfgRateBuilder),
+    /// Datum to set, CFG-DAT lines usually hold the current datum, see
+    /// [LegacyCfg::Dat]
+    DatSet(CfgDatSetBuilder),
+    PrtUart(CfgPrtUartRef<'a>),
+    PrtSpi(CfgPrtSpiRef<'a>),
+    PrtI2c(CfgPrtI2cRef<'a>),
+    Dat(CfgDatRef<'a>),
+    Nmea(CfgNmeaRef<'a>),
+    Usb(CfgUsbRef<'a>),
+    Nav5(CfgNav5Ref<'a>),
+    Logfilter(CfgLogfilterRef<'a>),
+    Hnr(CfgHnrRef<'a>),
+    Dgnss(CfgDgnssRef<'a>),
+}
+
+/// Contents of a u-center configuration file, see the [module](self)
+/// documentation for the format
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UcenterConfig {
+    entries: Vec<UcenterEntry>,
+}
+
+impl UcenterConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn entries(&self) -> &[UcenterEntry] {
+        &self.entries
+    }
+
+    pub fn push(&mut self, entry: UcenterEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Adds the message of a UBX frame, such as one written by a packet
+    /// builder, `false` if `frame` is not a UBX frame
+    pub fn push_frame(&mut self, frame: &[u8]) -> bool {
+        let Some(([sync1, sync2, class, id, len1, len2], rest)) = frame.split_first_chunk() else {
+            return false;
+        };
+        let len = usize::from(u16::from_le_bytes([*len1, *len2]));
+        if [*sync1, *sync2] != [UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2] || rest.len() != len + 2 {
+            return false;
+        }
+        self.entries.push(UcenterEntry::Message {
+            class: *class,
+            id: *id,
+            payload: rest[..len].to_vec(),
+        });
+        true
+    }
+
+    /// Configuration values set by the file, including those of CFG-VALGET
+    /// responses
+    pub fn cfg_vals(&self) -> Vec<CfgVal> {
+        let mut vals = Vec::new();
+        for entry in &self.entries {
+            match entry {
+                UcenterEntry::Set { val, .. } => vals.push(*val),
+                entry => vals.extend(entry.valget_values().unwrap_or_default()),
+            }
+        }
+        vals
+    }
+
+    /// UBX frames applying the file to a receiver.
+    ///
+    /// Values without a layer, and those of CFG-VALGET responses read from the
+    /// default layer, are set in `layers`. CFG messages are sent as they are,
+    /// other messages, such as MON-VER, are informational and skipped.
+    pub fn frames(&self, layers: CfgLayerSet) -> Vec<Vec<u8>> {
+        let mut frames = Vec::new();
+        let mut sets: Vec<(CfgLayerSet, CfgVal)> = Vec::new();
+        let mut deletes: Vec<(CfgLayerSet, CfgKey)> = Vec::new();
+        let or_default = |entry_layers: CfgLayerSet| {
+            if entry_layers.is_empty() {
+                layers
+            } else {
+                entry_layers
+            }
+        };
+
+        for entry in &self.entries {
+            match entry {
+                UcenterEntry::Set { layers, val } => {
+                    flush_deletes(&mut deletes, &mut frames);
+                    sets.push((or_default(*layers), *val));
+                },
+                UcenterEntry::Delete { layers, key } => {
+                    flush_sets(&mut sets, &mut frames);
+                    deletes.push((or_default(*layers), *key));
+                },
+                entry => {
+                    flush_deletes(&mut deletes, &mut frames);
+                    if let Some(vals) = entry.valget_values() {
+                        let valget_layers = match CfgValGetResponseRef(payload(entry)).layers() {
+                            CfgLayerGet::Ram => CfgLayerSet::RAM,
+                            CfgLayerGet::Bbr => CfgLayerSet::BBR,
+                            CfgLayerGet::Flash => CfgLayerSet::FLASH,
+                            _ => layers,
+                        };
+                        sets.extend(vals.into_iter().map(|val| (valget_layers, val)));
+                    } else if matches!(entry, UcenterEntry::Message { class: 0x06, .. }) {
+                        flush_sets(&mut sets, &mut frames);
+                        frames.extend(entry.frame());
+                    }
+                },
+            }
+        }
+        flush_sets(&mut sets, &mut frames);
+        flush_deletes(&mut deletes, &mut frames);
+        frames
+    }
+}
+
+fn payload(entry: &UcenterEntry) -> &[u8] {
+    match entry {
+        UcenterEntry::Message { payload, .. } => payload,
+        _ => &[],
+    }
+}
+
+/// Writes CFG-VALSET requests for runs of values set in the same layers
+fn flush_sets(sets: &mut Vec<(CfgLayerSet, CfgVal)>, frames: &mut Vec<Vec<u8>>) {
+    for run in sets.chunk_by(|a, b| a.0 == b.0) {
+        let vals: Vec<CfgVal> = run.iter().map(|(_, val)| *val).collect();
+        for cfg_data in vals.chunks(usize::from(MAX_CFG_KEYS)) {
+            let request = CfgValSetBuilder {
+                version: 0,
+                layers: run[0].0,
+                reserved1: 0,
+                cfg_data,
+            };
+            frames.push(request.into_packet_vec());
+        }
+    }
+    sets.clear();
+}
+
+/// Writes CFG-VALDEL requests for runs of keys deleted from the same layers
+fn flush_deletes(deletes: &mut Vec<(CfgLayerSet, CfgKey)>, frames: &mut Vec<Vec<u8>>) {
+    for run in deletes.chunk_by(|a, b| a.0 == b.0) {
+        let keys: Vec<CfgKey> = run.iter().map(|(_, key)| *key).collect();
+        for cfg_keys in keys.chunks(usize::from(MAX_CFG_KEYS)) {
+            let request = CfgValDelBuilder {
+                version: 0,
+                layers: run[0].0,
+                reserved1: 0,
+                cfg_keys,
+            };
+            frames.push(request.into_packet_vec());
+        }
+    }
+    deletes.clear();
+}
+
+const LAYERS: [(CfgLayerSet, &str); 3] = [
+    (CfgLayerSet::RAM, "RAM"),
+    (CfgLayerSet::BBR, "BBR"),
+    (CfgLayerSet::FLASH, "Flash"),
+];
+
+fn parse_layer(word: &str) -> Option<CfgLayerSet> {
+    LAYERS
+        .iter()
+        .find(|(_, name)| name.eq_ignore_ascii_case(word))
+        .map(|(layer, _)| *layer)
+}
+
+fn parse_value(word: &str) -> Option<ProfileValue> {
+    let (negative, digits) = match word.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, word),
+    };
+    let unsigned = match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => digits.parse().ok(),
+    };
+    match unsigned {
+        Some(value) if negative => 0i64.checked_sub_unsigned(value).map(ProfileValue::Signed),
+        Some(value) => Some(ProfileValue::Unsigned(value)),
+        None => word.parse().ok().map(ProfileValue::Float),
+    }
+}
+
+fn parse_message(hex: &str) -> Option<UcenterEntry> {
+    let bytes = hex
+        .split_whitespace()
+        .map(|byte| u8::from_str_radix(byte, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    let ([class, id, len1, len2], payload) = bytes.split_first_chunk()?;
+    if payload.len() != usize::from(u16::from_le_bytes([*len1, *len2])) {
+        return None;
+    }
+    Some(UcenterEntry::Message {
+        class: *class,
+        id: *id,
+        payload: payload.to_vec(),
+    })
+}
+
+impl FromStr for UcenterConfig {
+    type Err = UcenterError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut config = Self::new();
+        let mut delete = false;
+        for (line_number, line) in text.lines().enumerate() {
+            let line_number = line_number + 1;
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line.eq_ignore_ascii_case("[set]") || line.eq_ignore_ascii_case("[del]") {
+                delete = line.eq_ignore_ascii_case("[del]");
+                continue;
+            }
+            if let Some((_, hex)) = line.split_once(" - ") {
+                let entry = parse_message(hex).ok_or(UcenterError::InvalidMessage(line_number))?;
+                config.push(entry);
+                continue;
+            }
+
+            let mut words = line.split_whitespace().peekable();
+            let layers = match words.peek().and_then(|word| parse_layer(word)) {
+                Some(layers) => {
+                    words.next();
+                    layers
+                },
+                None => CfgLayerSet::empty(),
+            };
+            let words: Vec<&str> = words.collect();
+            let entry = match (delete, words.as_slice()) {
+                (false, [name, value]) => {
+                    let value = parse_value(value).ok_or(UcenterError::InvalidLine(line_number))?;
+                    let val = encode(name, &value)
+                        .map_err(|error| UcenterError::InvalidValue(line_number, error))?;
+                    UcenterEntry::Set { layers, val }
+                },
+                (true, [name]) => {
+                    let key = match CfgKeyInfo::from_name(name) {
+                        Some(info) => Some(info.key),
+                        None => name
+                            .strip_prefix("0x")
+                            .and_then(|id| u32::from_str_radix(id, 16).ok())
+                            .and_then(CfgKey::from_id),
+                    };
+                    let key = key.ok_or(UcenterError::InvalidValue(
+                        line_number,
+                        ProfileError::UnknownKey((*name).into()),
+                    ))?;
+                    UcenterEntry::Delete { layers, key }
+                },
+                _ => return Err(UcenterError::InvalidLine(line_number)),
+            };
+            config.push(entry);
+        }
+        Ok(config)
+    }
+}
+
+/// Writes the file, one line per layer of the values to set or delete
+impl fmt::Display for UcenterConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut delete = None;
+        for entry in &self.entries {
+            let (layers, name, value) = match entry {
+                UcenterEntry::Message { class, id, payload } => {
+                    write!(f, "{} -", message_name(*class, *id))?;
+                    let len = (payload.len() as u16).to_le_bytes();
+                    for byte in [*class, *id, len[0], len[1]].iter().chain(payload) {
+                        write!(f, " {byte:02X}")?;
+                    }
+                    writeln!(f)?;
+                    continue;
+                },
+                UcenterEntry::Set { layers, val } => {
+                    let (name, value) = decode(val, false);
+                    (*layers, name, Some(value))
+                },
+                UcenterEntry::Delete { layers, key } => {
+                    let name = match key.info() {
+                        Some(info) => info.name.into(),
+                        None => format!("0x{:08x}", *key as u32),
+                    };
+                    (*layers, name, None)
+                },
+            };
+
+            let is_delete = value.is_none();
+            if delete != Some(is_delete) {
+                writeln!(f, "{}", if is_delete { "[del]" } else { "[set]" })?;
+                delete = Some(is_delete);
+            }
+            let value = value.map(|value| match value {
+                ProfileValue::Bool(value) => format!(" {}", u8::from(value)),
+                ProfileValue::Unsigned(value) => format!(" {value}"),
+                ProfileValue::Signed(value) => format!(" {value}"),
+                ProfileValue::Float(value) => format!(" {value}"),
+                ProfileValue::Name(value) => format!(" {value}"),
+            });
+            let value = value.unwrap_or_default();
+            if layers.is_empty() {
+                writeln!(f, "{name}{value}")?;
+            }
+            for (layer, layer_name) in LAYERS {
+                if layers.contains(layer) {
+                    writeln!(f, "{layer_name:>5} {name}{value}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Name of a message in u-center files, e.g. `CFG-RATE`
+fn message_name(class: u8, id: u8) -> String {
+    let class_name = match class {
+        0x01 => "NAV",
+        0x02 => "RXM",
+        0x04 => "INF",
+        0x05 => "ACK",
+        0x06 => "CFG",
+        0x09 => "UPD",
+        0x0a => "MON",
+        0x0b => "AID",
+        0x0d => "TIM",
+        0x10 => "ESF",
+        0x13 => "MGA",
+        0x21 => "LOG",
+        0x27 => "SEC",
+        0x28 => "HNR",
+        _ => return format!("UBX-{class:02X}-{id:02X}"),
+    };
+    let id_name = match (class, id) {
+        (0x06, 0x00) => "PRT",
+        (0x06, 0x01) => "MSG",
+        (0x06, 0x02) => "INF",
+        (0x06, 0x04) => "RST",
+        (0x06, 0x06) => "DAT",
+        (0x06, 0x08) => "RATE",
+        (0x06, 0x09) => "CFG",
+        (0x06, 0x11) => "RXM",
+        (0x06, 0x13) => "ANT",
+        (0x06, 0x16) => "SBAS",
+        (0x06, 0x17) => "NMEA",
+        (0x06, 0x1b) => "USB",
+        (0x06, 0x1e) => "ODO",
+        (0x06, 0x23) => "NAVX5",
+        (0x06, 0x24) => "NAV5",
+        (0x06, 0x31) => "TP5",
+        (0x06, 0x34) => "RINV",
+        (0x06, 0x39) => "ITFM",
+        (0x06, 0x3b) => "PM2",
+        (0x06, 0x3d) => "TMODE2",
+        (0x06, 0x3e) => "GNSS",
+        (0x06, 0x47) => "LOGFILTER",
+        (0x06, 0x57) => "PWR",
+        (0x06, 0x5c) => "HNR",
+        (0x06, 0x62) => "SMGR",
+        (0x06, 0x69) => "GEOFENCE",
+        (0x06, 0x70) => "DGNSS",
+        (0x06, 0x71) => "TMODE3",
+        (0x06, 0x86) => "PMS",
+        (0x06, 0x8a) => "VALSET",
+        (0x06, 0x8b) => "VALGET",
+        (0x06, 0x8c) => "VALDEL",
+        (0x06, 0x93) => "BATCH",
+        (0x0a, 0x04) => "VER",
+        (0x0a, 0x09) => "HW",
+        _ => return format!("{class_name}-{id:02X}"),
+    };
+    format!("{class_name}-{id_name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_values() {
+        let config: UcenterConfig = "\
+            # rate and cable delay\n\
+            \n\
+            ram CFG-RATE-MEAS 0x64 # 10 Hz\n\
+            CFG-TP-ANT_CABLEDELAY -50\n\
+            CFG-NAVSPG-USRDAT_MAJA 6378137.5\n"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            config.entries(),
+            [
+                UcenterEntry::Set {
+                    layers: CfgLayerSet::RAM,
+                    val: CfgVal::RateMeas(100),
+                },
+                UcenterEntry::Set {
+                    layers: CfgLayerSet::empty(),
+                    val: CfgVal::TpAntCableDelay(-50),
+                },
+                UcenterEntry::Set {
+                    layers: CfgLayerSet::empty(),
+                    val: CfgVal::NavSpgUsrDatMaja(6378137.5),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_lines() {
+        let error = |text: &str| text.parse::<UcenterConfig>().unwrap_err();
+        assert_eq!(error("[set]\nCFG-RATE-MEAS"), UcenterError::InvalidLine(2));
+        assert_eq!(error("CFG-RATE-MEAS ten"), UcenterError::InvalidLine(1));
+        assert_eq!(
+            error("[del]\nRAM CFG-RATE-MEAS 100"),
+            UcenterError::InvalidLine(2)
+        );
+        assert_eq!(
+            error("CFG-RATE - 06 08 06 00 E8 03"),
+            UcenterError::InvalidMessage(1)
+        );
+        assert_eq!(
+            error("CFG-RATE - 06 08 0G 00"),
+            UcenterError::InvalidMessage(1)
+        );
+        assert_eq!(
+            error("CFG-RATE-MEAS 0x10000"),
+            UcenterError::InvalidValue(1, ProfileError::InvalidValue("CFG-RATE-MEAS".into()))
+        );
+        assert_eq!(
+            error("[del]\nCFG-RATE-SLOW"),
+            UcenterError::InvalidValue(2, ProfileError::UnknownKey("CFG-RATE-SLOW".into()))
+        );
+    }
+
+    #[test]
+    fn message_names() {
+        assert_eq!(message_name(0x06, 0x8a), "CFG-VALSET");
+        assert_eq!(message_name(0x0a, 0x36), "MON-36");
+        assert_eq!(message_name(0xf0, 0x00), "UBX-F0-00");
+    }
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "ubx_proto33")]
+
+//! A proptest generator for U-Blox NAV-PVAT messages.
+//!
+//! This module provides a `proptest` strategy to generate byte-level
+//! UBX frames containing a NAV-PVAT message. The generated data is
+//! structurally correct, including sync words, class/ID, length,
+//! a randomized valid payload, and a correct checksum.
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use proptest::prelude::*;
+use ublox::{ParserBuilder, PositionLLA, UbxPacket, Velocity};
+
+mod common;
+use common::build_ubx_frame;
+
+/// Represents the payload of a UBX-NAV-PVAT message.
+///
+/// Only the fields exercised by the test are randomized; date and time
+/// fields are kept in range so the message stays well formed.
+///
+/// NAV-PVAT payload is 116 bytes.
+#[derive(Debug, Clone)]
+pub struct NavPvatPayload {
+    pub itow: u32,           // GPS time of week of the navigation epoch [ms]
+    pub flags: u8,           // Fix status flags
+    pub lon: i32,            // Longitude [1e-7 deg]
+    pub lat: i32,            // Latitude [1e-7 deg]
+    pub height_msl: i32,     // Height above mean sea level [mm]
+    pub g_speed: i32,        // Ground speed [mm/s]
+    pub veh_roll: i32,       // Vehicle roll [1e-5 deg]
+    pub veh_pitch: i32,      // Vehicle pitch [1e-5 deg]
+    pub veh_heading: i32,    // Vehicle heading [1e-5 deg]
+    pub mot_heading: i32,    // Motion heading [1e-5 deg]
+    pub acc_heading: u16,    // Vehicle heading accuracy [1e-2 deg]
+    pub ellipse_orient: u16, // Error ellipse orientation [1e-2 deg]
+    pub ellipse_major: u32,  // Error ellipse semi-major axis [mm]
+    pub ellipse_minor: u32,  // Error ellipse semi-minor axis [mm]
+}
+
+impl NavPvatPayload {
+    /// Serializes the NavPvatPayload into a 116-byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut wtr = Vec::with_capacity(116);
+        wtr.write_u32::<LittleEndian>(self.itow).unwrap();
+        wtr.write_u8(0).unwrap(); // version
+        wtr.write_u8(0x07).unwrap(); // valid
+        wtr.write_u16::<LittleEndian>(2024).unwrap(); // year
+        wtr.extend_from_slice(&[6, 15, 12, 30, 45]); // month, day, hour, min, sec
+        wtr.extend_from_slice(&[0; 3]); // reserved0, reserved1
+        wtr.write_u32::<LittleEndian>(20).unwrap(); // tAcc
+        wtr.write_i32::<LittleEndian>(0).unwrap(); // nano
+        wtr.write_u8(3).unwrap(); // fixType
+        wtr.write_u8(self.flags).unwrap();
+        wtr.write_u8(0).unwrap(); // flags2
+        wtr.write_u8(12).unwrap(); // numSV
+        wtr.write_i32::<LittleEndian>(self.lon).unwrap();
+        wtr.write_i32::<LittleEndian>(self.lat).unwrap();
+        wtr.write_i32::<LittleEndian>(self.height_msl + 40_000)
+            .unwrap(); // height
+        wtr.write_i32::<LittleEndian>(self.height_msl).unwrap();
+        wtr.write_u32::<LittleEndian>(1_000).unwrap(); // hAcc
+        wtr.write_u32::<LittleEndian>(2_000).unwrap(); // vAcc
+        wtr.extend_from_slice(&[0; 12]); // velN, velE, velD
+        wtr.write_i32::<LittleEndian>(self.g_speed).unwrap();
+        wtr.write_u32::<LittleEndian>(100).unwrap(); // sAcc
+        wtr.write_i32::<LittleEndian>(self.veh_roll).unwrap();
+        wtr.write_i32::<LittleEndian>(self.veh_pitch).unwrap();
+        wtr.write_i32::<LittleEndian>(self.veh_heading).unwrap();
+        wtr.write_i32::<LittleEndian>(self.mot_heading).unwrap();
+        wtr.write_u16::<LittleEndian>(50).unwrap(); // accRoll
+        wtr.write_u16::<LittleEndian>(60).unwrap(); // accPitch
+        wtr.write_u16::<LittleEndian>(self.acc_heading).unwrap();
+        wtr.write_i16::<LittleEndian>(0).unwrap(); // magDec
+        wtr.write_u16::<LittleEndian>(0).unwrap(); // magAcc
+        wtr.write_u16::<LittleEndian>(self.ellipse_orient).unwrap();
+        wtr.write_u32::<LittleEndian>(self.ellipse_major).unwrap();
+        wtr.write_u32::<LittleEndian>(self.ellipse_minor).unwrap();
+        wtr.extend_from_slice(&[0; 8]); // reserved2, reserved3
+        wtr
+    }
+}
+
+/// A proptest strategy for generating a `NavPvatPayload` struct.
+fn nav_pvat_payload_strategy() -> impl Strategy<Value = NavPvatPayload> {
+    (
+        (
+            any::<u32>(),
+            any::<u8>(),
+            -1_800_000_000i32..=1_800_000_000,
+            -900_000_000i32..=900_000_000,
+            -1_000_000i32..=10_000_000,
+            0i32..=100_000,
+            -18_000_000i32..=18_000_000,
+        ),
+        (
+            -9_000_000i32..=9_000_000,
+            0i32..=36_000_000,
+            0i32..=36_000_000,
+            any::<u16>(),
+            0u16..=18_000,
+            any::<u32>(),
+            any::<u32>(),
+        ),
+    )
+        .prop_map(
+            |(
+                (itow, flags, lon, lat, height_msl, g_speed, veh_roll),
+                (
+                    veh_pitch,
+                    veh_heading,
+                    mot_heading,
+                    acc_heading,
+                    ellipse_orient,
+                    ellipse_major,
+                    ellipse_minor,
+                ),
+            )| NavPvatPayload {
+                itow,
+                flags,
+                lon,
+                lat,
+                height_msl,
+                g_speed,
+                veh_roll,
+                veh_pitch,
+                veh_heading,
+                mot_heading,
+                acc_heading,
+                ellipse_orient,
+                ellipse_major,
+                ellipse_minor,
+            },
+        )
+}
+
+/// A proptest strategy that generates a complete, valid UBX frame
+/// containing a NAV-PVAT message, along with the source payload struct.
+pub fn ubx_nav_pvat_frame_strategy() -> impl Strategy<Value = (NavPvatPayload, Vec<u8>)> {
+    nav_pvat_payload_strategy().prop_map(|payload_struct| {
+        let payload = payload_struct.to_bytes();
+
+        let final_frame = build_ubx_frame(0x01, 0x17, &payload);
+
+        (payload_struct, final_frame)
+    })
+}
+
+proptest! {
+    #[test]
+    fn test_parser_proto33_with_generated_nav_pvat_frames((expected, frame) in ubx_nav_pvat_frame_strategy()) {
+        use ublox::proto33::{PacketRef, Proto33};
+
+        let mut parser = ParserBuilder::new().with_protocol::<Proto33>().with_fixed_buffer::<1024>();
+        let mut it = parser.consume_ubx(&frame);
+
+        let Some(Ok(UbxPacket::Proto33(PacketRef::NavPvat(p)))) = it.next() else {
+            panic!("Parser failed to parse a NAV-PVAT valid packet");
+        };
+
+        prop_assert_eq!(p.itow(), expected.itow);
+        prop_assert_eq!(p.flags().bits(), expected.flags);
+        prop_assert_eq!(p.vehicle_roll_raw(), expected.veh_roll);
+        prop_assert_eq!(p.vehicle_pitch_raw(), expected.veh_pitch);
+        prop_assert_eq!(p.vehicle_heading_raw(), expected.veh_heading);
+        prop_assert_eq!(p.heading_motion_raw(), expected.mot_heading);
+        prop_assert_eq!(p.heading_accuracy_raw(), expected.acc_heading);
+        prop_assert_eq!(p.err_ellipse_orientation_raw(), expected.ellipse_orient);
+        prop_assert_eq!(p.err_ellipse_major_meters_raw(), expected.ellipse_major);
+        prop_assert_eq!(p.err_ellipse_minor_meters_raw(), expected.ellipse_minor);
+        prop_assert!((p.vehicle_heading() - expected.veh_heading as f64 * 1e-5).abs() < 1e-9);
+
+        let lla = PositionLLA::from(&p);
+        prop_assert!((lla.lon - expected.lon as f64 * 1e-7).abs() < 1e-9);
+        prop_assert!((lla.lat - expected.lat as f64 * 1e-7).abs() < 1e-9);
+        prop_assert!((lla.alt - expected.height_msl as f64 * 1e-3).abs() < 1e-9);
+
+        let vel = Velocity::from(&p);
+        prop_assert!((vel.speed - expected.g_speed as f64 * 1e-3).abs() < 1e-9);
+        prop_assert!((vel.heading - expected.mot_heading as f64 * 1e-5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,213 @@
+#![cfg(any(
+    feature = "ubx_proto27",
+    feature = "ubx_proto31",
+    feature = "ubx_proto33",
+))]
+
+//! A proptest generator for U-Blox NAV-SVIN messages.
+//!
+//! This module provides a `proptest` strategy to generate byte-level
+//! UBX frames containing a NAV-SVIN message. The generated data is
+//! structurally correct, including sync words, class/ID, length,
+//! a randomized valid payload, and a correct checksum.
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use proptest::prelude::*;
+use ublox::{ParserBuilder, PositionECEF, UbxPacket};
+
+mod common;
+use common::build_ubx_frame;
+
+/// Represents the payload of a UBX-NAV-SVIN message.
+///
+/// The fields are ordered as they appear in the u-blox documentation.
+///
+/// NAV-SVIN payload is 40 bytes.
+#[derive(Debug, Clone)]
+pub struct NavSvinPayload {
+    pub itow: u32,     // GPS time of week of the navigation epoch [ms]
+    pub dur: u32,      // Passed survey-in observation time [s]
+    pub mean_x: i32,   // Mean position ECEF X coordinate [cm]
+    pub mean_y: i32,   // Mean position ECEF Y coordinate [cm]
+    pub mean_z: i32,   // Mean position ECEF Z coordinate [cm]
+    pub mean_x_hp: i8, // High precision component of X [0.1 mm]
+    pub mean_y_hp: i8, // High precision component of Y [0.1 mm]
+    pub mean_z_hp: i8, // High precision component of Z [0.1 mm]
+    pub mean_acc: u32, // Mean position accuracy [0.1 mm]
+    pub obs: u32,      // Number of position observations
+    pub valid: u8,     // Survey-in position validity flag
+    pub active: u8,    // Survey-in in progress flag
+}
+
+impl NavSvinPayload {
+    /// Serializes the NavSvinPayload into a 40-byte vector.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut wtr = Vec::with_capacity(40);
+        wtr.write_u8(0).unwrap(); // version
+        wtr.extend_from_slice(&[0; 3]); // reserved1
+        wtr.write_u32::<LittleEndian>(self.itow).unwrap();
+        wtr.write_u32::<LittleEndian>(self.dur).unwrap();
+        wtr.write_i32::<LittleEndian>(self.mean_x).unwrap();
+        wtr.write_i32::<LittleEndian>(self.mean_y).unwrap();
+        wtr.write_i32::<LittleEndian>(self.mean_z).unwrap();
+        wtr.write_i8(self.mean_x_hp).unwrap();
+        wtr.write_i8(self.mean_y_hp).unwrap();
+        wtr.write_i8(self.mean_z_hp).unwrap();
+        wtr.write_u8(0).unwrap(); // reserved2
+        wtr.write_u32::<LittleEndian>(self.mean_acc).unwrap();
+        wtr.write_u32::<LittleEndian>(self.obs).unwrap();
+        wtr.write_u8(self.valid).unwrap();
+        wtr.write_u8(self.active).unwrap();
+        wtr.extend_from_slice(&[0; 2]); // reserved3
+        wtr
+    }
+}
+
+/// A proptest strategy for generating a `NavSvinPayload` struct.
+fn nav_svin_payload_strategy() -> impl Strategy<Value = NavSvinPayload> {
+    (
+        (
+            any::<u32>(),
+            any::<u32>(),
+            any::<i32>(),
+            any::<i32>(),
+            any::<i32>(),
+        ),
+        (
+            -99i8..=99,
+            -99i8..=99,
+            -99i8..=99,
+            any::<u32>(),
+            any::<u32>(),
+            0u8..=1,
+            0u8..=1,
+        ),
+    )
+        .prop_map(
+            |(
+                (itow, dur, mean_x, mean_y, mean_z),
+                (mean_x_hp, mean_y_hp, mean_z_hp, mean_acc, obs, valid, active),
+            )| NavSvinPayload {
+                itow,
+                dur,
+                mean_x,
+                mean_y,
+                mean_z,
+                mean_x_hp,
+                mean_y_hp,
+                mean_z_hp,
+                mean_acc,
+                obs,
+                valid,
+                active,
+            },
+        )
+}
+
+/// A proptest strategy that generates a complete, valid UBX frame
+/// containing a NAV-SVIN message, along with the source payload struct.
+pub fn ubx_nav_svin_frame_strategy() -> impl Strategy<Value = (NavSvinPayload, Vec<u8>)> {
+    nav_svin_payload_strategy().prop_map(|payload_struct| {
+        let payload = payload_struct.to_bytes();
+
+        let final_frame = build_ubx_frame(0x01, 0x3B, &payload);
+
+        (payload_struct, final_frame)
+    })
+}
+
+fn expected_ecef(expected: &NavSvinPayload) -> PositionECEF {
+    PositionECEF {
+        x: expected.mean_x as f64 * 1e-2 + expected.mean_x_hp as f64 * 1e-4,
+        y: expected.mean_y as f64 * 1e-2 + expected.mean_y_hp as f64 * 1e-4,
+        z: expected.mean_z as f64 * 1e-2 + expected.mean_z_hp as f64 * 1e-4,
+    }
+}
+
+#[cfg(feature = "ubx_proto27")]
+proptest! {
+    #[test]
+    fn test_parser_proto27_with_generated_nav_svin_frames((expected, frame) in ubx_nav_svin_frame_strategy()) {
+        use ublox::proto27::{PacketRef, Proto27};
+
+        let mut parser = ParserBuilder::new().with_protocol::<Proto27>().with_fixed_buffer::<1024>();
+        let mut it = parser.consume_ubx(&frame);
+
+        let Some(Ok(UbxPacket::Proto27(PacketRef::NavSvin(p)))) = it.next() else {
+            panic!("Parser failed to parse a NAV-SVIN valid packet");
+        };
+
+        prop_assert_eq!(p.itow(), expected.itow);
+        prop_assert_eq!(p.dur(), expected.dur);
+        prop_assert_eq!(p.mean_x_meters_raw(), expected.mean_x);
+        prop_assert_eq!(p.mean_y_meters_raw(), expected.mean_y);
+        prop_assert_eq!(p.mean_z_meters_raw(), expected.mean_z);
+        prop_assert_eq!(p.mean_x_hp_meters_raw(), expected.mean_x_hp);
+        prop_assert_eq!(p.mean_y_hp_meters_raw(), expected.mean_y_hp);
+        prop_assert_eq!(p.mean_z_hp_meters_raw(), expected.mean_z_hp);
+        prop_assert_eq!(p.mean_acc_meters_raw(), expected.mean_acc);
+        prop_assert_eq!(p.obs(), expected.obs);
+        prop_assert_eq!(p.valid(), expected.valid == 1);
+        prop_assert_eq!(p.active(), expected.active == 1);
+
+        let ecef = PositionECEF::from(&p);
+        let want = expected_ecef(&expected);
+        prop_assert!((ecef.x - want.x).abs() < 1e-6);
+        prop_assert!((ecef.y - want.y).abs() < 1e-6);
+        prop_assert!((ecef.z - want.z).abs() < 1e-6);
+    }
+}
+
+#[cfg(feature = "ubx_proto31")]
+proptest! {
+    #[test]
+    fn test_parser_proto31_with_generated_nav_svin_frames((expected, frame) in ubx_nav_svin_frame_strategy()) {
+        use ublox::proto31::{PacketRef, Proto31};
+
+        let mut parser = ParserBuilder::new().with_protocol::<Proto31>().with_fixed_buffer::<1024>();
+        let mut it = parser.consume_ubx(&frame);
+
+        let Some(Ok(UbxPacket::Proto31(PacketRef::NavSvin(p)))) = it.next() else {
+            panic!("Parser failed to parse a NAV-SVIN valid packet");
+        };
+
+        prop_assert_eq!(p.itow(), expected.itow);
+        prop_assert_eq!(p.mean_acc_meters_raw(), expected.mean_acc);
+        prop_assert_eq!(p.obs(), expected.obs);
+        prop_assert_eq!(p.valid(), expected.valid == 1);
+        prop_assert_eq!(p.active(), expected.active == 1);
+
+        let ecef = PositionECEF::from(&p);
+        let want = expected_ecef(&expected);
+        prop_assert!((ecef.x - want.x).abs() < 1e-6);
+        prop_assert!((ecef.y - want.y).abs() < 1e-6);
+        prop_assert!((ecef.z - want.z).abs() < 1e-6);
+    }
+}
+
+#[cfg(feature = "ubx_proto33")]
+proptest! {
+    #[test]
+    fn test_parser_proto33_with_generated_nav_svin_frames((expected, frame) in ubx_nav_svin_frame_strategy()) {
+        use ublox::proto33::{PacketRef, Proto33};
+
+        let mut parser = ParserBuilder::new().with_protocol::<Proto33>().with_fixed_buffer::<1024>();
+        let mut it = parser.consume_ubx(&frame);
+
+        let Some(Ok(UbxPacket::Proto33(PacketRef::NavSvin(p)))) = it.next() else {
+            panic!("Parser failed to parse a NAV-SVIN valid packet");
+        };
+
+        prop_assert_eq!(p.itow(), expected.itow);
+        prop_assert_eq!(p.mean_acc_meters_raw(), expected.mean_acc);
+        prop_assert_eq!(p.obs(), expected.obs);
+        prop_assert_eq!(p.valid(), expected.valid == 1);
+        prop_assert_eq!(p.active(), expected.active == 1);
+
+        let ecef = PositionECEF::from(&p);
+        let want = expected_ecef(&expected);
+        prop_assert!((ecef.x - want.x).abs() < 1e-6);
+        prop_assert!((ecef.y - want.y).abs() < 1e-6);
+        prop_assert!((ecef.z - want.z).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,274 @@
+#![cfg(feature = "alloc")]
+
+use ublox::{
+    cfg_dgnss::CfgDgnssMode,
+    cfg_msg::CfgMsgAllPortsBuilder,
+    cfg_nav5::{NavDynamicModel, NavFixMode},
+    cfg_rate::{AlignmentToReferenceTime, CfgRateBuilder},
+    cfg_val::{CfgKey, CfgVal},
+    packets::cfg_val::{CfgLayerSet, CfgValDelBuilder, CfgValSetBuilder},
+    ucenter::{LegacyCfg, UcenterConfig, UcenterEntry},
+};
+
+const M8: &str = include_str!("ucenter/m8.txt");
+const GEN9: &str = include_str!("ucenter/gen9.txt");
+
+#[test]
+fn m8_round_trip() {
+    let config: UcenterConfig = M8.parse().unwrap();
+    assert_eq!(config.entries().len(), 5);
+    assert_eq!(config.to_string(), M8);
+    assert!(config.cfg_vals().is_empty());
+
+    let rate = CfgRateBuilder {
+        measure_rate_ms: 1000,
+        nav_rate: 1,
+        time_ref: AlignmentToReferenceTime::Gps,
+    }
+    .into_packet_bytes();
+    // NAV-PVT on UART1
+    let msg = CfgMsgAllPortsBuilder {
+        msg_class: 0x01,
+        msg_id: 0x07,
+        rates: [0, 1, 0, 0, 0, 0],
+    }
+    .into_packet_bytes();
+    assert_eq!(config.entries()[2].frame().unwrap(), rate);
+    assert_eq!(config.entries()[4].frame().unwrap(), msg);
+
+    // MON-VER is informational and not sent
+    let frames = config.frames(CfgLayerSet::RAM);
+    assert_eq!(frames.len(), 4);
+    assert_eq!(frames[1], rate);
+    assert_eq!(frames[3], msg);
+
+    let mut rebuilt = UcenterConfig::new();
+    for entry in config.entries() {
+        assert!(rebuilt.push_frame(&entry.frame().unwrap()));
+    }
+    assert_eq!(rebuilt, config);
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn m8_packets() {
+    use ublox::proto23::{PacketRef, Proto23};
+
+    let config: UcenterConfig = M8.parse().unwrap();
+    let packets: Vec<_> = config
+        .entries()
+        .iter()
+        .map(|entry| entry.packet::<Proto23>().unwrap())
+        .collect();
+
+    let Ok(PacketRef::MonVer(ver)) = &packets[0] else {
+        panic!("{:?}", packets[0]);
+    };
+    assert_eq!(ver.software_version(), "ROM CORE 3.01 (107888)");
+    assert_eq!(ver.hardware_version(), "00080000");
+    assert_eq!(
+        ver.extension().collect::<Vec<_>>(),
+        ["FWVER=SPG 3.01", "PROTVER=18.00"]
+    );
+
+    let Ok(PacketRef::CfgPrtUart(prt)) = &packets[1] else {
+        panic!("{:?}", packets[1]);
+    };
+    assert_eq!(prt.baud_rate(), 9600);
+
+    let Ok(PacketRef::CfgNav5(nav5)) = &packets[3] else {
+        panic!("{:?}", packets[3]);
+    };
+    assert_eq!(nav5.dyn_model(), NavDynamicModel::Automotive);
+    assert_eq!(nav5.fix_mode(), NavFixMode::Auto2D3D);
+
+    // CFG-RATE and CFG-MSG are only known to the crate as requests
+    assert!(matches!(packets[2], Ok(PacketRef::Unknown(_))));
+    assert!(matches!(packets[4], Ok(PacketRef::Unknown(_))));
+}
+
+#[test]
+fn m8_legacy_cfg() {
+    let config: UcenterConfig = M8.parse().unwrap();
+    let entries = config.entries();
+    assert!(entries[0].legacy_cfg().is_none());
+
+    let Some(LegacyCfg::PrtUart(prt)) = entries[1].legacy_cfg() else {
+        panic!("CFG-PRT is not a UART port configuration");
+    };
+    assert_eq!(prt.baud_rate(), 9600);
+
+    let Some(LegacyCfg::Rate(rate)) = entries[2].legacy_cfg() else {
+        panic!("CFG-RATE not mapped");
+    };
+    assert_eq!(rate.measure_rate_ms, 1000);
+    assert_eq!(rate.nav_rate, 1);
+    assert_eq!(rate.time_ref, AlignmentToReferenceTime::Gps);
+    assert_eq!(
+        rate.into_packet_bytes().as_slice(),
+        entries[2].frame().unwrap()
+    );
+
+    let Some(LegacyCfg::Nav5(nav5)) = entries[3].legacy_cfg() else {
+        panic!("CFG-NAV5 not mapped");
+    };
+    assert_eq!(nav5.dyn_model(), NavDynamicModel::Automotive);
+
+    let Some(LegacyCfg::MsgAllPorts(msg)) = entries[4].legacy_cfg() else {
+        panic!("CFG-MSG not mapped");
+    };
+    assert_eq!((msg.msg_class, msg.msg_id), (0x01, 0x07));
+    assert_eq!(msg.rates, [0, 1, 0, 0, 0, 0]);
+    assert_eq!(
+        msg.into_packet_bytes().as_slice(),
+        entries[4].frame().unwrap()
+    );
+}
+
+#[test]
+fn legacy_cfg_messages() {
+    let config: UcenterConfig = "\
+        CFG-MSG - 06 01 03 00 01 07 01\n\
+        CFG-HNR - 06 5C 04 00 0A 00 00 00\n\
+        CFG-DGNSS - 06 70 04 00 03 00 00 00\n\
+        CFG-RATE - 06 08 06 00 E8 03 01 00 09 00\n"
+        .parse()
+        .unwrap();
+    let entries = config.entries();
+
+    let Some(LegacyCfg::MsgSinglePort(msg)) = entries[0].legacy_cfg() else {
+        panic!("CFG-MSG not mapped");
+    };
+    assert_eq!((msg.msg_class, msg.msg_id, msg.rate), (0x01, 0x07, 1));
+
+    let Some(LegacyCfg::Hnr(hnr)) = entries[1].legacy_cfg() else {
+        panic!("CFG-HNR not mapped");
+    };
+    assert_eq!(hnr.high_nav_rate(), 10);
+
+    let Some(LegacyCfg::Dgnss(dgnss)) = entries[2].legacy_cfg() else {
+        panic!("CFG-DGNSS not mapped");
+    };
+    assert_eq!(dgnss.dgnss_mode(), CfgDgnssMode::RtkFixed);
+
+    // invalid time reference
+    assert!(entries[3].legacy_cfg().is_none());
+}
+
+#[test]
+fn gen9_round_trip() {
+    let config: UcenterConfig = GEN9.parse().unwrap();
+    assert_eq!(config.to_string(), GEN9);
+
+    let entries = config.entries();
+    assert_eq!(entries.len(), 11);
+    assert_eq!(
+        entries[1],
+        UcenterEntry::Delete {
+            layers: CfgLayerSet::FLASH,
+            key: CfgKey::RateMeas,
+        }
+    );
+    assert_eq!(
+        entries[4],
+        UcenterEntry::Set {
+            layers: CfgLayerSet::RAM,
+            val: CfgVal::NavSpgDynModel(NavDynamicModel::Automotive),
+        }
+    );
+
+    let vals = [
+        CfgVal::RateMeas(100),
+        CfgVal::RateMeas(100),
+        CfgVal::NavSpgDynModel(NavDynamicModel::Automotive),
+        CfgVal::Uart1Baudrate(115200),
+        CfgVal::MsgOutUbxNavPvtUart1(1),
+        CfgVal::TpAntCableDelay(-50),
+        CfgVal::TpTp1Ena(true),
+        CfgVal::NavSpgUsrDatMaja(6378137.0),
+    ];
+    let valget = [CfgVal::RateMeas(100), CfgVal::MsgOutUbxNavPvtUart1(1)];
+    assert_eq!(config.cfg_vals(), [&vals[..], &valget[..]].concat());
+
+    let frames = config.frames(CfgLayerSet::RAM | CfgLayerSet::BBR);
+    let delete = CfgValDelBuilder {
+        version: 0,
+        layers: CfgLayerSet::FLASH,
+        reserved1: 0,
+        cfg_keys: &[CfgKey::RateMeas],
+    };
+    let set = |layers, cfg_data| {
+        CfgValSetBuilder {
+            version: 0,
+            layers,
+            reserved1: 0,
+            cfg_data,
+        }
+        .into_packet_vec()
+    };
+    assert_eq!(
+        frames,
+        [
+            delete.into_packet_vec(),
+            set(CfgLayerSet::RAM, &vals[..1]),
+            set(CfgLayerSet::BBR, &vals[1..2]),
+            // the CFG-VALGET response was read from RAM
+            set(CfgLayerSet::RAM, &[&vals[2..], &valget[..]].concat()),
+        ]
+    );
+}
+
+#[test]
+fn write_multiple_layers() {
+    let mut config = UcenterConfig::new();
+    config.push(UcenterEntry::Set {
+        layers: CfgLayerSet::RAM | CfgLayerSet::FLASH,
+        val: CfgVal::RateMeas(200),
+    });
+    config.push(UcenterEntry::Set {
+        layers: CfgLayerSet::empty(),
+        val: CfgVal::NavSpgUsrDatMaja(6378137.5),
+    });
+    config.push(UcenterEntry::Delete {
+        layers: CfgLayerSet::BBR,
+        key: CfgKey::NavSpgDynModel,
+    });
+    assert!(config.push_frame(
+        &CfgValDelBuilder {
+            version: 0,
+            layers: CfgLayerSet::FLASH,
+            reserved1: 0,
+            cfg_keys: &[CfgKey::Uart1Baudrate],
+        }
+        .into_packet_vec()
+    ));
+    assert_eq!(
+        config.to_string(),
+        "[set]\n\
+        \x20 RAM CFG-RATE-MEAS 200\n\
+        Flash CFG-RATE-MEAS 200\n\
+        CFG-NAVSPG-USRDAT_MAJA 6378137.5\n\
+        [del]\n\
+        \x20 BBR CFG-NAVSPG-DYNMODEL\n\
+        CFG-VALDEL - 06 8C 08 00 00 04 00 00 01 00 52 40\n"
+    );
+    let parsed: UcenterConfig = config.to_string().parse().unwrap();
+    assert_eq!(parsed.entries().len(), 5);
+    assert_eq!(parsed.to_string(), config.to_string());
+}
+
+#[test]
+fn delete_wildcard() {
+    let mut config = UcenterConfig::new();
+    config.push(UcenterEntry::Delete {
+        layers: CfgLayerSet::FLASH,
+        key: CfgKey::WildcardAll,
+    });
+    config.push(UcenterEntry::Delete {
+        layers: CfgLayerSet::BBR,
+        key: CfgKey::WildcardRate,
+    });
+    let text = config.to_string();
+    assert_eq!(text, "[del]\nFlash 0x7fffffff\n  BBR 0x7f21ffff\n");
+    assert_eq!(text.parse::<UcenterConfig>().unwrap(), config);
+}
@@ -19,6 +19,7 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
     let mut serializers = Vec::with_capacity(recv_packs.all_packets.len());
 
     let mut len_matches_ref = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut payload_matches_ref = Vec::with_capacity(recv_packs.all_packets.len());
 
     for name in &recv_packs.all_packets {
         let ref_name = format_ident!("{}Ref", name);
@@ -66,6 +67,9 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
         len_matches_ref.push(quote! {
             #union_enum_name_ref::#name(ref packet) => packet.payload_len(),
         });
+        payload_matches_ref.push(quote! {
+            #union_enum_name_ref::#name(ref packet) => packet.as_bytes(),
+        });
     }
 
     let unknown_var_ref = format_ident!("{}Ref", &recv_packs.unknown_ty);
@@ -140,6 +144,15 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
                     #union_enum_name_ref::Unknown(ref pack) => pack.payload.len(),
                 }
             }
+
+            /// Payload of the packet, as received
+            #[inline]
+            pub fn payload(&self) -> &[u8] {
+                match *self {
+                    #(#payload_matches_ref)*
+                    #union_enum_name_ref::Unknown(ref pack) => pack.payload,
+                }
+            }
         }
         impl #union_enum_name_owned {
             pub fn class_and_msg_id(&self) -> (u8, u8) {
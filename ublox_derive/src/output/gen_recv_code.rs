@@ -350,6 +350,9 @@ fn process_variable_size_field<'a>(
 }
 
 fn remove_lifetimes(mut ty: syn::Type) -> syn::Type {
+    if let syn::Type::Reference(type_ref) = &mut ty {
+        type_ref.lifetime = None;
+    }
     if let syn::Type::Path(type_path) = &mut ty {
         for segment in &mut type_path.path.segments {
             // Only process angle-bracketed args
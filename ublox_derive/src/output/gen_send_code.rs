@@ -29,8 +29,9 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
         let size_bytes = match f.size_bytes {
             Some(x) => x.get(),
             None => {
-                if repeatable_block_seen {
-                    // Tail variable-size field after iterator
+                if repeatable_block_seen || f.is_reference() {
+                    // Tail variable-size field after iterator, or a field
+                    // mapped to a byte slice.
                     // Requires an into fn to be defined in order to
                     // return this field as bytes
                     let into_fn = &f
@@ -47,6 +48,8 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
                             out.extend(bytes.iter().copied());
                         }
                     });
+
+                    builder_needs_lifetime = true;
                 } else {
                     // First repeatable block field: treat as iterator
                     extend_fields.push(quote! {
@@ -85,7 +88,7 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
         extend_fields.push(pack_fields.last().unwrap().clone());
         extend_fields.push(quote! {
             len_bytes += bytes.len();
-            out.extend(bytes);
+            out.extend(bytes.iter().copied());
         });
 
         for i in 0..size_bytes {
@@ -47,7 +47,15 @@ pub(super) fn generate_serialize_impl(
     let fields = pack_descr.fields.iter().map(|field| {
         let field_name = &field.name;
         let field_accessor = field.intermediate_field_name();
-        if field.size_bytes.is_some() || field.is_optional() {
+        if field.size_bytes.is_some()
+            && field.is_field_raw_ty_byte_array()
+            && !field.has_intermediate_type()
+        {
+            // Serialized as a slice, serde only supports arrays up to 32 elements
+            quote! {
+                state.serialize_entry(stringify!(#field_name), &self.#field_accessor()[..])?;
+            }
+        } else if field.size_bytes.is_some() || field.is_optional() || field.is_reference() {
             quote! {
                 state.serialize_entry(stringify!(#field_name), &self.#field_accessor())?;
             }
@@ -315,7 +315,7 @@ fn test_ubx_packet_recv_simple() {
                     state.serialize_entry(stringify!(itow), &self.itow())?;
                     state.serialize_entry(stringify!(lat), &self.lat_degrees())?;
                     state.serialize_entry(stringify!(a), &self.a())?;
-                    state.serialize_entry(stringify!(reserved1), &self.reserved1())?;
+                    state.serialize_entry(stringify!(reserved1), &self.reserved1()[..])?;
                     state.serialize_entry(stringify!(flags), &self.flags())?;
                     state.serialize_entry(stringify!(b), &self.b())?;
                     Ok(())
@@ -769,6 +769,16 @@ fn test_define_recv_packets() {
                         PacketRef::Unknown(ref pack) => pack.payload.len(),
                     }
                 }
+
+                /// Payload of the packet, as received
+                #[inline]
+                pub fn payload(&self) -> &[u8] {
+                    match *self {
+                        PacketRef::Pack1(ref packet) => packet.as_bytes(),
+                        PacketRef::Pack2(ref packet) => packet.as_bytes(),
+                        PacketRef::Unknown(ref pack) => pack.payload,
+                    }
+                }
             }
             impl PacketOwned {
                 pub fn class_and_msg_id(&self) -> (u8, u8) {
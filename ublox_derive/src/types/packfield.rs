@@ -25,6 +25,13 @@ impl PackField {
             .is_some_and(|m| crate::type_is_option(&m.ty))
     }
 
+    pub fn is_reference(&self) -> bool {
+        self.map
+            .map_type
+            .as_ref()
+            .is_some_and(|m| matches!(m.ty, Type::Reference(_)))
+    }
+
     pub fn has_intermediate_type(&self) -> bool {
         self.map.map_type.is_some()
     }